@@ -0,0 +1,130 @@
+//! Llamadas al contrato desplegado a traves de `cargo contract call`.
+//!
+//! Los argumentos se escriben con la sintaxis de valores SCALE que entiende cargo-contract:
+//! los textos van entre comillas y los vectores de tuplas como `[(1, true), (2, false)]`.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::metadata::Metadata;
+
+const INSTALAR_CARGO_CONTRACT: &str = "cargo install --force --locked cargo-contract";
+
+pub struct Conexion {
+    pub url: String,
+    pub suri: String,
+    pub contrato: String,
+    pub artefacto: PathBuf, //metadata o bundle .contract del sistema de votacion.
+    pub metadata: Metadata,
+}
+
+impl Conexion {
+    /// Arma los argumentos de `cargo contract call`. Si ejecutar es false solo se simula la llamada.
+    pub fn argumentos(&self, mensaje: &str, args: &[String], ejecutar: bool) -> Vec<String> {
+        let mut argumentos = vec![
+            String::from("contract"),
+            String::from("call"),
+            self.artefacto.display().to_string(),
+            String::from("--contract"),
+            self.contrato.clone(),
+            String::from("--message"),
+            String::from(mensaje),
+            String::from("--suri"),
+            self.suri.clone(),
+            String::from("--url"),
+            self.url.clone(),
+        ];
+        if ejecutar {
+            argumentos.push(String::from("--execute"));
+            argumentos.push(String::from("--skip-confirm"));
+        }
+        //--args recibe varios valores, por eso va al final.
+        if !args.is_empty() {
+            argumentos.push(String::from("--args"));
+            argumentos.extend(args.iter().cloned());
+        }
+        argumentos
+    }
+
+    /// Controla el mensaje contra la metadata, lo llama y devuelve la salida de cargo-contract.
+    /// Las consultas (mensajes que no modifican) nunca se envian como transaccion.
+    pub fn llamar(&self, mensaje: &str, args: &[String], ejecutar: bool) -> Result<String, String> {
+        let modifica = self.metadata.verificar(mensaje, args.len())?.modifica;
+        let salida = Command::new("cargo")
+            .args(self.argumentos(mensaje, args, ejecutar && modifica))
+            .output()
+            .map_err(|e| format!("No se pudo ejecutar cargo contract: {e}"))?;
+        if !salida.status.success() {
+            return Err(format!("{mensaje} fallo: {}", String::from_utf8_lossy(&salida.stderr).trim()));
+        }
+        Ok(String::from_utf8_lossy(&salida.stdout).into_owned())
+    }
+}
+
+/// Controla que `cargo contract` este instalado antes de hacer cualquier llamada.
+pub fn verificar_cargo_contract() -> Result<(), String> {
+    verificar_herramienta("cargo")
+}
+
+fn verificar_herramienta(cargo: &str) -> Result<(), String> {
+    match Command::new(cargo).args(["contract", "--version"]).output() {
+        Ok(salida) if salida.status.success() => Ok(()),
+        Ok(_) => Err(format!("cargo-contract no esta instalado, se instala con `{INSTALAR_CARGO_CONTRACT}`")),
+        Err(e) => Err(format!("No se pudo ejecutar {cargo} ({e}), hace falta Rust con cargo-contract: `{INSTALAR_CARGO_CONTRACT}`")),
+    }
+}
+
+/// Texto entre comillas, escapando las comillas y barras que tenga adentro.
+pub fn texto(valor: &str) -> String {
+    format!("\"{}\"", valor.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Vector de decisiones (id_usuario, valido) para validar_usuarios.
+pub fn decisiones(valores: &[(i16, bool)]) -> String {
+    let tuplas: Vec<String> = valores.iter().map(|(id, valido)| format!("({id}, {valido})")).collect();
+    format!("[{}]", tuplas.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conexion() -> Conexion {
+        Conexion {
+            url: String::from("ws://127.0.0.1:9944"),
+            suri: String::from("//Alice"),
+            contrato: String::from("5Contrato"),
+            artefacto: PathBuf::from("sistema_de_votacion.contract"),
+            metadata: Metadata { contrato: String::from("sistema_de_votacion"), mensajes: Vec::new() },
+        }
+    }
+
+    #[test]
+    fn arma_la_llamada_simulada_y_la_ejecutada() {
+        let simulada = conexion().argumentos("aprobar_reporte", &[String::from("2")], false);
+        assert_eq!(
+            simulada.join(" "),
+            "contract call sistema_de_votacion.contract --contract 5Contrato --message aprobar_reporte --suri //Alice --url ws://127.0.0.1:9944 --args 2"
+        );
+        let ejecutada = conexion().argumentos("version_de_almacenamiento", &[], true);
+        assert!(ejecutada.ends_with(&[String::from("--execute"), String::from("--skip-confirm")]));
+    }
+
+    #[test]
+    fn formatea_valores() {
+        assert_eq!(texto("Presidente"), "\"Presidente\"");
+        assert_eq!(texto("dice \"hola\""), "\"dice \\\"hola\\\"\"");
+        assert_eq!(decisiones(&[(1, true), (2, false)]), "[(1, true), (2, false)]");
+    }
+
+    #[test]
+    fn avisa_como_instalar_cargo_contract() {
+        let error = verificar_herramienta("cargo-que-no-existe").unwrap_err();
+        assert!(error.contains(INSTALAR_CARGO_CONTRACT));
+    }
+
+    #[test]
+    fn no_llama_mensajes_que_no_existen() {
+        assert!(conexion().llamar("crear_eleccion", &[], false).is_err());
+    }
+}
\ No newline at end of file
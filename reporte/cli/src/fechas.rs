@@ -0,0 +1,76 @@
+//! Conversion de las fechas que escribe el operador a los enteros dia, mes y anio que recibe el contrato.
+
+/// Fecha del calendario tal como la esperan crear_eleccion y crear_referendum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fecha {
+    pub anio: i32,
+    pub mes: i32,
+    pub dia: i32,
+}
+
+impl Fecha {
+    /// Acepta `AAAA-MM-DD` o `DD/MM/AAAA` y controla que el dia exista en ese mes.
+    pub fn parsear(texto: &str) -> Result<Fecha, String> {
+        let texto = texto.trim();
+        let partes: Vec<&str> = if texto.contains('-') {
+            texto.split('-').collect()
+        } else {
+            texto.split('/').rev().collect()
+        };
+        if partes.len() != 3 {
+            return Err(format!("Fecha invalida '{texto}', se espera AAAA-MM-DD o DD/MM/AAAA"));
+        }
+        let numero = |parte: &str| -> Result<i32, String> {
+            parte.trim().parse::<i32>().map_err(|_| format!("Fecha invalida '{texto}'"))
+        };
+        let fecha = Fecha { anio: numero(partes[0])?, mes: numero(partes[1])?, dia: numero(partes[2])? };
+        if fecha.anio < 1970 {
+            return Err(format!("Fecha invalida '{texto}', el anio debe ser 1970 o posterior"));
+        }
+        if fecha.dia < 1 || fecha.dia > dias_en_mes(fecha.anio, fecha.mes) {
+            return Err(format!("Fecha invalida '{texto}', el dia no existe en ese mes"));
+        }
+        Ok(fecha)
+    }
+}
+
+/// Misma regla que usa el contrato, 0 si el mes no existe.
+fn dias_en_mes(anio: i32, mes: i32) -> i32 {
+    match mes {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if (anio % 4 == 0 && anio % 100 != 0) || anio % 400 == 0 => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsea_los_dos_formatos() {
+        let esperada = Fecha { anio: 2024, mes: 10, dia: 15 };
+        assert_eq!(Fecha::parsear("2024-10-15"), Ok(esperada));
+        assert_eq!(Fecha::parsear("15/10/2024"), Ok(esperada));
+        assert_eq!(Fecha::parsear(" 2024-10-15 "), Ok(esperada));
+    }
+
+    #[test]
+    fn rechaza_fechas_inexistentes() {
+        assert!(Fecha::parsear("2023-02-29").is_err());
+        assert!(Fecha::parsear("2024-02-29").is_ok());
+        assert!(Fecha::parsear("31/04/2024").is_err());
+        assert!(Fecha::parsear("2024-13-01").is_err());
+        assert!(Fecha::parsear("15-10").is_err());
+        assert!(Fecha::parsear("manana").is_err());
+        assert!(Fecha::parsear("1969-12-31").is_err());
+    }
+
+    #[test]
+    fn ordena_cronologicamente() {
+        assert!(Fecha::parsear("2024-10-15").unwrap() < Fecha::parsear("2024-11-01").unwrap());
+        assert!(Fecha::parsear("2025-01-01").unwrap() > Fecha::parsear("2024-12-31").unwrap());
+    }
+}
\ No newline at end of file
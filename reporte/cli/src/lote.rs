@@ -0,0 +1,62 @@
+//! Lectura del CSV de decisiones para validar postulantes en lote.
+//!
+//! Cada fila es `id_usuario,valido`. La primera fila puede ser un encabezado, las filas vacias
+//! y las que empiezan con `#` se ignoran. valido acepta true/false, si/no o 1/0.
+
+/// Cantidad maxima de decisiones que acepta validar_usuarios en una llamada.
+pub const MAX_TAMANIO_DE_LOTE: usize = 100;
+
+/// Devuelve las decisiones (id_usuario, valido) en el orden del archivo.
+pub fn parsear_decisiones(contenido: &str) -> Result<Vec<(i16, bool)>, String> {
+    let mut decisiones = Vec::new();
+    for (i, linea) in contenido.lines().enumerate() {
+        let linea = linea.trim();
+        if linea.is_empty() || linea.starts_with('#') {
+            continue;
+        }
+        let campos: Vec<&str> = linea.split([',', ';']).map(str::trim).collect();
+        if campos.len() != 2 {
+            return Err(format!("Linea {}: se esperan dos columnas, id_usuario y valido", i + 1));
+        }
+        let id_usuario = match campos[0].parse::<i16>() {
+            Ok(id) if id > 0 => id,
+            Ok(_) => return Err(format!("Linea {}: el id de usuario debe ser mayor a 0", i + 1)),
+            Err(_) if decisiones.is_empty() && i == 0 => continue, //encabezado
+            Err(_) => return Err(format!("Linea {}: id de usuario invalido '{}'", i + 1, campos[0])),
+        };
+        let valido = match campos[1].to_lowercase().as_str() {
+            "true" | "si" | "1" => true,
+            "false" | "no" | "0" => false,
+            otro => return Err(format!("Linea {}: valor de valido invalido '{otro}'", i + 1)),
+        };
+        if decisiones.iter().any(|(id, _)| *id == id_usuario) {
+            return Err(format!("Linea {}: el usuario {id_usuario} esta repetido", i + 1));
+        }
+        decisiones.push((id_usuario, valido));
+    }
+    if decisiones.is_empty() {
+        return Err(String::from("El archivo no tiene decisiones"));
+    }
+    Ok(decisiones)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lee_encabezado_comentarios_y_valores() {
+        let contenido = "id_usuario,valido\n# aprobados por la junta\n1,true\n\n2; no\n3,SI\r\n4,0\n";
+        assert_eq!(parsear_decisiones(contenido), Ok(vec![(1, true), (2, false), (3, true), (4, false)]));
+    }
+
+    #[test]
+    fn informa_la_linea_con_error() {
+        assert_eq!(parsear_decisiones("1,true\n2,tal vez"), Err(String::from("Linea 2: valor de valido invalido 'tal vez'")));
+        assert_eq!(parsear_decisiones("1,true\nx,true"), Err(String::from("Linea 2: id de usuario invalido 'x'")));
+        assert_eq!(parsear_decisiones("1,true\n1,false"), Err(String::from("Linea 2: el usuario 1 esta repetido")));
+        assert!(parsear_decisiones("0,true").is_err());
+        assert!(parsear_decisiones("1,true,extra").is_err());
+        assert!(parsear_decisiones("id_usuario,valido\n").is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,160 @@
+//! Herramienta de linea de comandos para operar un despliegue del sistema de votacion.
+//!
+//! Carga la metadata del contrato y llama a sus mensajes con `cargo contract call` contra el
+//! nodo indicado (por defecto un substrate-contracts-node local en ws://127.0.0.1:9944).
+//! Sin `--ejecutar` las llamadas solo se simulan, lo que permite revisar el resultado antes de firmar.
+//!
+//! EJEMPLO:
+//! ```text
+//! votacion --contrato 5F... --metadata target/ink/sistema_de_votacion.json \
+//!     crear-eleccion --cargo Presidente --inicio 2024-10-15 --fin 20/11/2024 --ejecutar
+//! votacion --contrato 5F... --metadata ... validar-lote --eleccion 1 --csv decisiones.csv --ejecutar
+//! ```
+
+mod contrato;
+mod fechas;
+mod lote;
+mod metadata;
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use contrato::Conexion;
+use fechas::Fecha;
+use metadata::Metadata;
+
+#[derive(Parser)]
+#[command(name = "votacion", about = "Opera un despliegue del contrato SistemaDeVotacion")]
+struct Cli {
+    /// Nodo al que se envian las llamadas.
+    #[arg(long, env = "VOTACION_URL", default_value = "ws://127.0.0.1:9944", global = true)]
+    url: String,
+    /// Cuenta que firma, en formato URI secreto.
+    #[arg(long, env = "VOTACION_SURI", default_value = "//Alice", global = true)]
+    suri: String,
+    /// Direccion del contrato SistemaDeVotacion desplegado.
+    #[arg(long, env = "VOTACION_CONTRATO")]
+    contrato: String,
+    /// Metadata (.json) o bundle (.contract) generado por cargo contract build.
+    #[arg(long, env = "VOTACION_METADATA")]
+    metadata: PathBuf,
+    /// Envia la transaccion; si no se indica la llamada solo se simula.
+    #[arg(long, global = true)]
+    ejecutar: bool,
+    #[command(subcommand)]
+    comando: Comando,
+}
+
+#[derive(Subcommand)]
+enum Comando {
+    /// Crea una eleccion, las fechas van como AAAA-MM-DD o DD/MM/AAAA.
+    CrearEleccion {
+        #[arg(long)]
+        cargo: String,
+        #[arg(long)]
+        inicio: String,
+        #[arg(long)]
+        fin: String,
+    },
+    /// Registra un usuario con la cuenta que firma.
+    RegistrarUsuario {
+        #[arg(long)]
+        nombre: String,
+        #[arg(long)]
+        apellido: String,
+        #[arg(long)]
+        dni: String,
+    },
+    /// Valida o rechaza postulantes leyendo un CSV con filas id_usuario,valido.
+    ValidarLote {
+        #[arg(long)]
+        eleccion: i16,
+        #[arg(long)]
+        csv: PathBuf,
+        /// Si alguna decision de un lote falla no se aplica ninguna de ese lote.
+        #[arg(long)]
+        atomico: bool,
+    },
+    /// Aprueba un contrato de reporte pendiente.
+    AprobarReporte {
+        #[arg(long)]
+        id: i16,
+    },
+    /// Muestra la version del almacenamiento, la pausa y una pagina de usuarios y elecciones.
+    Estado {
+        #[arg(long, default_value_t = 0)]
+        desde: u32,
+        #[arg(long, default_value_t = 50)]
+        cantidad: u32,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match ejecutar(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn ejecutar(cli: Cli) -> Result<(), String> {
+    contrato::verificar_cargo_contract()?;
+    let conexion = Conexion {
+        metadata: Metadata::cargar(&cli.metadata)?,
+        url: cli.url,
+        suri: cli.suri,
+        contrato: cli.contrato,
+        artefacto: cli.metadata,
+    };
+    match cli.comando {
+        Comando::CrearEleccion { cargo, inicio, fin } => {
+            let inicio = Fecha::parsear(&inicio)?;
+            let fin = Fecha::parsear(&fin)?;
+            if inicio >= fin {
+                return Err(String::from("La fecha de inicio debe ser anterior a la fecha de fin"));
+            }
+            let args = vec![
+                contrato::texto(&cargo),
+                inicio.dia.to_string(),
+                inicio.mes.to_string(),
+                inicio.anio.to_string(),
+                fin.dia.to_string(),
+                fin.mes.to_string(),
+                fin.anio.to_string(),
+            ];
+            print!("{}", conexion.llamar("crear_eleccion", &args, cli.ejecutar)?);
+        }
+        Comando::RegistrarUsuario { nombre, apellido, dni } => {
+            let args = vec![contrato::texto(&nombre), contrato::texto(&apellido), contrato::texto(&dni)];
+            print!("{}", conexion.llamar("registrar_usuario", &args, cli.ejecutar)?);
+        }
+        Comando::ValidarLote { eleccion, csv, atomico } => {
+            let contenido = fs::read_to_string(&csv).map_err(|e| format!("No se pudo leer {}: {e}", csv.display()))?;
+            let decisiones = lote::parsear_decisiones(&contenido)?;
+            for (i, parte) in decisiones.chunks(lote::MAX_TAMANIO_DE_LOTE).enumerate() {
+                let args = vec![eleccion.to_string(), contrato::decisiones(parte), atomico.to_string()];
+                println!("Lote {} ({} decisiones):", i + 1, parte.len());
+                print!("{}", conexion.llamar("validar_usuarios", &args, cli.ejecutar)?);
+            }
+        }
+        Comando::AprobarReporte { id } => {
+            print!("{}", conexion.llamar("aprobar_reporte", &[id.to_string()], cli.ejecutar)?);
+        }
+        Comando::Estado { desde, cantidad } => {
+            let pagina = [desde.to_string(), cantidad.to_string()];
+            let filtro = String::from("{ estado: None, desde: None, hasta: None, cargo: None }");
+            print!("{}", conexion.llamar("version_de_almacenamiento", &[], false)?);
+            print!("{}", conexion.llamar("pausa_del_sistema", &[], false)?);
+            print!("{}", conexion.llamar("obtener_usuarios_paginado", &pagina, false)?);
+            let args = [pagina[0].clone(), pagina[1].clone(), filtro];
+            print!("{}", conexion.llamar("obtener_elecciones_paginado", &args, false)?);
+        }
+    }
+    Ok(())
+}
\ No newline at end of file
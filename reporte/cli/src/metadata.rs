@@ -0,0 +1,103 @@
+//! Lectura de la metadata del contrato (`.json` o `.contract`) que genera `cargo contract build`.
+//!
+//! Se usa para controlar, antes de llamar al nodo, que el mensaje exista en el contrato desplegado
+//! y que reciba la cantidad de argumentos que le vamos a pasar.
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mensaje {
+    pub nombre: String,
+    pub argumentos: Vec<String>,
+    pub modifica: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metadata {
+    pub contrato: String,
+    pub mensajes: Vec<Mensaje>,
+}
+
+impl Metadata {
+    pub fn cargar(ruta: &Path) -> Result<Metadata, String> {
+        let contenido = fs::read_to_string(ruta)
+            .map_err(|e| format!("No se pudo leer la metadata {}: {e}", ruta.display()))?;
+        Metadata::desde_json(&contenido)
+    }
+
+    pub fn desde_json(contenido: &str) -> Result<Metadata, String> {
+        let json: Value = serde_json::from_str(contenido).map_err(|e| format!("La metadata no es un JSON valido: {e}"))?;
+        let contrato = json["contract"]["name"].as_str().unwrap_or_default().to_string();
+        let mensajes = json["spec"]["messages"]
+            .as_array()
+            .ok_or_else(|| String::from("La metadata no tiene spec.messages"))?
+            .iter()
+            .map(|mensaje| Mensaje {
+                nombre: mensaje["label"].as_str().unwrap_or_default().to_string(),
+                argumentos: mensaje["args"]
+                    .as_array()
+                    .map(|args| args.iter().filter_map(|a| a["label"].as_str().map(String::from)).collect())
+                    .unwrap_or_default(),
+                modifica: mensaje["mutates"].as_bool().unwrap_or(false),
+            })
+            .collect();
+        Ok(Metadata { contrato, mensajes })
+    }
+
+    /// Busca el mensaje y controla la cantidad de argumentos.
+    pub fn verificar(&self, nombre: &str, cantidad_de_argumentos: usize) -> Result<&Mensaje, String> {
+        let mensaje = self
+            .mensajes
+            .iter()
+            .find(|m| m.nombre == nombre)
+            .ok_or_else(|| format!("El contrato {} no tiene el mensaje {nombre}", self.contrato))?;
+        if mensaje.argumentos.len() != cantidad_de_argumentos {
+            return Err(format!(
+                "El mensaje {nombre} recibe {} argumentos ({}) y se enviaron {cantidad_de_argumentos}",
+                mensaje.argumentos.len(),
+                mensaje.argumentos.join(", ")
+            ));
+        }
+        Ok(mensaje)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"{
+        "contract": { "name": "sistema_de_votacion", "version": "0.1.0" },
+        "spec": {
+            "constructors": [],
+            "messages": [
+                { "label": "registrar_usuario", "mutates": true, "payable": false,
+                  "args": [ { "label": "nombre" }, { "label": "apellido" }, { "label": "dni" } ] },
+                { "label": "version_de_almacenamiento", "mutates": false, "payable": false, "args": [] }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn lee_los_mensajes() {
+        let metadata = Metadata::desde_json(METADATA).unwrap();
+        assert_eq!(metadata.contrato, "sistema_de_votacion");
+        assert_eq!(metadata.mensajes.len(), 2);
+        assert_eq!(metadata.mensajes[0].argumentos, vec!["nombre", "apellido", "dni"]);
+        assert!(metadata.mensajes[0].modifica);
+        assert!(!metadata.mensajes[1].modifica);
+    }
+
+    #[test]
+    fn verifica_nombre_y_argumentos() {
+        let metadata = Metadata::desde_json(METADATA).unwrap();
+        assert!(metadata.verificar("registrar_usuario", 3).is_ok());
+        assert!(metadata.verificar("registrar_usuario", 2).is_err());
+        assert!(metadata.verificar("votar_a_candidato", 3).is_err());
+        assert!(Metadata::desde_json("{}").is_err());
+        assert!(Metadata::desde_json("no es json").is_err());
+    }
+}
\ No newline at end of file
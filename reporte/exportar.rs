@@ -0,0 +1,165 @@
+use ink::prelude::format;
+use ink::prelude::string::{String, ToString};
+use ink::prelude::vec::Vec;
+
+/// Columnas de exportar_resultado, una fila por candidato o por opcion si es un referendum.
+pub const COLUMNAS_DE_RESULTADO:&[&str] = &["cargo", "numero_de_boleta", "nombre", "apellido", "partido", "votos", "porcentaje"];
+/// Columnas de exportar_participacion, una sola fila. porcentaje_de_votacion es sobre los aprobados.
+pub const COLUMNAS_DE_PARTICIPACION:&[&str] = &["votantes_aprobados", "votos_emitidos", "porcentaje_de_votacion", "votantes_postulados", "abstenciones", "porcentaje_sobre_postulados"];
+/// Columnas de exportar_eleccion, una fila por votante postulado, aprobado o rechazado.
+pub const COLUMNAS_DE_ELECCION:&[&str] = &["estado", "nombre", "apellido", "dni", "motivo_de_rechazo"];
+
+#[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo)
+)]
+pub enum Formato {
+    Csv,
+    Json,
+}
+
+#[derive(Debug,Clone,PartialEq)]
+pub enum Valor {
+    Texto(String),
+    Entero(i64),
+    Porcentaje(u32),//en puntos basicos, 1234 es 12.34%.
+}
+
+/// Tabla con columnas fijas. El CSV lleva los nombres de las columnas en la primera fila y el JSON es un arreglo
+/// de objetos con esos nombres como claves. Los porcentajes se escriben con dos decimales y punto, por ejemplo 12.34.
+#[derive(Debug,Clone,PartialEq)]
+pub struct Tabla {
+    columnas:&'static [&'static str],
+    filas:Vec<Vec<Valor>>,
+}
+
+impl Tabla {
+    pub fn new(columnas:&'static [&'static str]) -> Self {
+        Tabla { columnas, filas:Vec::new() }
+    }
+
+    /// Agrega una fila, debe tener un valor por columna.
+    pub fn agregar_fila(&mut self, fila:Vec<Valor>) {
+        debug_assert_eq!(fila.len(), self.columnas.len());
+        self.filas.push(fila);
+    }
+
+    pub fn exportar(&self, formato:Formato) -> String {
+        match formato {
+            Formato::Csv => self.a_csv(),
+            Formato::Json => self.a_json(),
+        }
+    }
+
+    fn a_csv(&self) -> String {
+        let mut salida = self.columnas.join(",");
+        salida.push_str("\r\n");
+        for fila in &self.filas {
+            let campos:Vec<String> = fila.iter().map(|valor| match valor {
+                Valor::Texto(texto) => campo_csv(texto),
+                otro => texto_de_numero(otro),
+            }).collect();
+            salida.push_str(&campos.join(","));
+            salida.push_str("\r\n");
+        }
+        salida
+    }
+
+    fn a_json(&self) -> String {
+        let objetos:Vec<String> = self.filas.iter().map(|fila| {
+            let pares:Vec<String> = self.columnas.iter().zip(fila).map(|(columna, valor)| {
+                let valor = match valor {
+                    Valor::Texto(texto) => texto_json(texto),
+                    otro => texto_de_numero(otro),
+                };
+                format!("{}:{}", texto_json(columna), valor)
+            }).collect();
+            format!("{{{}}}", pares.join(","))
+        }).collect();
+        format!("[{}]", objetos.join(","))
+    }
+}
+
+/// Porcentaje de parte sobre total en puntos basicos, 0 si el total no es positivo.
+pub fn porcentaje(parte:i64, total:i64) -> u32 {
+    if total <= 0 || parte <= 0 {
+        return 0;
+    }
+    (parte.saturating_mul(10_000) / total) as u32
+}
+
+fn texto_de_numero(valor:&Valor) -> String {
+    match valor {
+        Valor::Entero(numero) => numero.to_string(),
+        Valor::Porcentaje(puntos) => format!("{}.{:02}", puntos / 100, puntos % 100),
+        Valor::Texto(texto) => texto.clone(),
+    }
+}
+
+/// Entre comillas solo si tiene comas, comillas o saltos de linea, duplicando las comillas.
+fn campo_csv(texto:&str) -> String {
+    if texto.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", texto.replace('"', "\"\""))
+    } else {
+        String::from(texto)
+    }
+}
+
+fn texto_json(texto:&str) -> String {
+    let mut salida = String::from("\"");
+    for caracter in texto.chars() {
+        match caracter {
+            '"' => salida.push_str("\\\""),
+            '\\' => salida.push_str("\\\\"),
+            '\n' => salida.push_str("\\n"),
+            '\r' => salida.push_str("\\r"),
+            '\t' => salida.push_str("\\t"),
+            c if (c as u32) < 0x20 => salida.push_str(&format!("\\u{:04x}", c as u32)),
+            c => salida.push(c),
+        }
+    }
+    salida.push('"');
+    salida
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::prelude::vec;
+
+    fn tabla() -> Tabla {
+        let mut tabla = Tabla::new(COLUMNAS_DE_RESULTADO);
+        tabla.agregar_fila(vec![
+            Valor::Texto(String::from("Presidente")),
+            Valor::Entero(1),
+            Valor::Texto(String::from("Carlos")),
+            Valor::Texto(String::from("Sanchez")),
+            Valor::Texto(String::from("Partido \"Azul\", unido")),
+            Valor::Entero(3),
+            Valor::Porcentaje(porcentaje(3, 4)),
+        ]);
+        tabla
+    }
+
+    #[test]
+    fn exporta_csv_con_encabezado_y_comillas(){
+        assert_eq!(tabla().exportar(Formato::Csv), "cargo,numero_de_boleta,nombre,apellido,partido,votos,porcentaje\r\nPresidente,1,Carlos,Sanchez,\"Partido \"\"Azul\"\", unido\",3,75.00\r\n");
+        assert_eq!(Tabla::new(COLUMNAS_DE_PARTICIPACION).exportar(Formato::Csv), "votantes_aprobados,votos_emitidos,porcentaje_de_votacion,votantes_postulados,abstenciones,porcentaje_sobre_postulados\r\n");
+    }
+
+    #[test]
+    fn exporta_json_con_las_mismas_columnas(){
+        assert_eq!(tabla().exportar(Formato::Json), "[{\"cargo\":\"Presidente\",\"numero_de_boleta\":1,\"nombre\":\"Carlos\",\"apellido\":\"Sanchez\",\"partido\":\"Partido \\\"Azul\\\", unido\",\"votos\":3,\"porcentaje\":75.00}]");
+        assert_eq!(Tabla::new(COLUMNAS_DE_ELECCION).exportar(Formato::Json), "[]");
+    }
+
+    #[test]
+    fn porcentajes_sin_division_por_cero(){
+        assert_eq!(porcentaje(1, 3), 3333);
+        assert_eq!(porcentaje(5, 0), 0);
+        assert_eq!(porcentaje(0, 7), 0);
+        assert_eq!(texto_de_numero(&Valor::Porcentaje(5)), "0.05");
+        assert_eq!(texto_de_numero(&Valor::Porcentaje(10_000)), "100.00");
+    }
+}
\ No newline at end of file
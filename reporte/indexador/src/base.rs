@@ -0,0 +1,396 @@
+//! Modelo de lectura en SQLite: usuarios, elecciones, postulaciones, votos y reportes.
+//!
+//! Cada bloque se guarda en una transaccion junto con su numero, asi el indexador puede cortarse y
+//! retomar desde el ultimo bloque guardado sin contar nada dos veces. Las fechas son milisegundos.
+
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use crate::bloque::{hex, Bloque, Cuenta};
+use crate::decodificar::{decodificar_evento, decodificar_llamada, Mensaje, Suceso};
+
+const ESQUEMA: &str = "
+CREATE TABLE IF NOT EXISTS progreso (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    ultimo_bloque INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS usuarios (
+    id INTEGER PRIMARY KEY,
+    cuenta TEXT NOT NULL,
+    nombre TEXT,
+    apellido TEXT,
+    dni TEXT,
+    bloque INTEGER NOT NULL,
+    registrado_en INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS elecciones (
+    id INTEGER PRIMARY KEY,
+    cargo TEXT NOT NULL,
+    inicio INTEGER NOT NULL,
+    fin INTEGER NOT NULL,
+    creada_en INTEGER NOT NULL,
+    finalizada_en INTEGER,
+    hash_del_resultado TEXT
+);
+CREATE TABLE IF NOT EXISTS postulaciones (
+    id_eleccion INTEGER NOT NULL,
+    id_usuario INTEGER NOT NULL,
+    rol TEXT NOT NULL,
+    id_cargo INTEGER,
+    estado TEXT NOT NULL,
+    postulado_en INTEGER NOT NULL,
+    resuelta_en INTEGER,
+    PRIMARY KEY (id_eleccion, id_usuario)
+);
+CREATE TABLE IF NOT EXISTS votos (
+    comprobante TEXT PRIMARY KEY,
+    id_eleccion INTEGER NOT NULL,
+    tipo TEXT NOT NULL,
+    bloque INTEGER NOT NULL,
+    emitido_en INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS votos_por_eleccion ON votos (id_eleccion, emitido_en);
+CREATE TABLE IF NOT EXISTS conteo_de_votos (
+    id_eleccion INTEGER NOT NULL,
+    tipo TEXT NOT NULL,
+    objetivo INTEGER NOT NULL,
+    votos INTEGER NOT NULL,
+    PRIMARY KEY (id_eleccion, tipo, objetivo)
+);
+CREATE TABLE IF NOT EXISTS reportes (
+    cuenta TEXT PRIMARY KEY,
+    estado TEXT NOT NULL,
+    actualizado_en INTEGER NOT NULL
+);
+";
+
+/// Tablas que tienen la columna id_eleccion, se renumeran cuando se elimina una eleccion.
+const TABLAS_POR_ELECCION: [&str; 3] = ["postulaciones", "votos", "conteo_de_votos"];
+
+const MILISEGUNDOS_POR_HORA: u64 = 3_600_000;
+
+pub struct Base {
+    conexion: Connection,
+}
+
+/// Resolucion de una postulacion, lo que devuelve la consulta de un usuario.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Postulacion {
+    pub id_eleccion: i16,
+    pub rol: String,
+    pub estado: String,
+    pub postulado_en: u64,
+    pub resuelta_en: Option<u64>,
+}
+
+fn error(e: rusqlite::Error) -> String {
+    format!("Error de la base: {e}")
+}
+
+impl Base {
+    pub fn abrir(ruta: &Path) -> Result<Base, String> {
+        Base::con(Connection::open(ruta).map_err(error)?)
+    }
+
+    #[cfg(test)]
+    pub fn en_memoria() -> Result<Base, String> {
+        Base::con(Connection::open_in_memory().map_err(error)?)
+    }
+
+    fn con(conexion: Connection) -> Result<Base, String> {
+        conexion.execute_batch(ESQUEMA).map_err(error)?;
+        Ok(Base { conexion })
+    }
+
+    pub fn ultimo_bloque(&self) -> Result<Option<u64>, String> {
+        self.conexion
+            .query_row("SELECT ultimo_bloque FROM progreso WHERE id = 1", [], |fila| fila.get(0))
+            .optional()
+            .map_err(error)
+    }
+
+    /// Aplica las llamadas exitosas al sistema del bloque. Los bloques ya indexados se ignoran y
+    /// devuelve false.
+    pub fn indexar(&mut self, bloque: &Bloque, sistema: &Cuenta) -> Result<bool, String> {
+        if self.ultimo_bloque()?.is_some_and(|ultimo| bloque.numero <= ultimo) {
+            return Ok(false);
+        }
+        let tx = self.conexion.transaction().map_err(error)?;
+        for llamada in bloque.llamadas.iter().filter(|l| l.exito) {
+            let mensaje = if llamada.contrato == *sistema { decodificar_llamada(&llamada.datos)? } else { Mensaje::Otro };
+            for evento in llamada.eventos.iter().filter(|e| e.contrato == *sistema) {
+                aplicar(&tx, bloque, &mensaje, decodificar_evento(evento)?).map_err(error)?;
+            }
+        }
+        tx.execute(
+            "INSERT INTO progreso (id, ultimo_bloque) VALUES (1, ?1) ON CONFLICT (id) DO UPDATE SET ultimo_bloque = ?1",
+            params![bloque.numero],
+        )
+        .map_err(error)?;
+        tx.commit().map_err(error)?;
+        Ok(true)
+    }
+
+    /// Votos emitidos en la eleccion agrupados por hora, (comienzo de la hora, votos) en orden.
+    pub fn votos_por_hora(&self, id_eleccion: i16) -> Result<Vec<(u64, u64)>, String> {
+        let mut consulta = self
+            .conexion
+            .prepare(
+                "SELECT (emitido_en / ?2) * ?2 AS hora, COUNT(*) FROM votos WHERE id_eleccion = ?1 GROUP BY hora ORDER BY hora",
+            )
+            .map_err(error)?;
+        let filas = consulta
+            .query_map(params![id_eleccion, MILISEGUNDOS_POR_HORA], |fila| Ok((fila.get(0)?, fila.get(1)?)))
+            .map_err(error)?;
+        filas.collect::<Result<_, _>>().map_err(error)
+    }
+
+    /// Postulaciones del usuario en todas las elecciones, con cuando se postulo y cuando se resolvio.
+    pub fn postulaciones_de_usuario(&self, id_usuario: i16) -> Result<Vec<Postulacion>, String> {
+        let mut consulta = self
+            .conexion
+            .prepare(
+                "SELECT id_eleccion, rol, estado, postulado_en, resuelta_en FROM postulaciones WHERE id_usuario = ?1 ORDER BY id_eleccion",
+            )
+            .map_err(error)?;
+        let filas = consulta
+            .query_map(params![id_usuario], |fila| {
+                Ok(Postulacion {
+                    id_eleccion: fila.get(0)?,
+                    rol: fila.get(1)?,
+                    estado: fila.get(2)?,
+                    postulado_en: fila.get(3)?,
+                    resuelta_en: fila.get(4)?,
+                })
+            })
+            .map_err(error)?;
+        filas.collect::<Result<_, _>>().map_err(error)
+    }
+
+    /// Votos por tipo y objetivo (candidato, boleta, opcion o lista; 0 en blanco) de la eleccion.
+    pub fn conteo_de_votos(&self, id_eleccion: i16) -> Result<Vec<(String, i16, u64)>, String> {
+        let mut consulta = self
+            .conexion
+            .prepare("SELECT tipo, objetivo, votos FROM conteo_de_votos WHERE id_eleccion = ?1 ORDER BY tipo, objetivo")
+            .map_err(error)?;
+        let filas = consulta
+            .query_map(params![id_eleccion], |fila| Ok((fila.get(0)?, fila.get(1)?, fila.get(2)?)))
+            .map_err(error)?;
+        filas.collect::<Result<_, _>>().map_err(error)
+    }
+}
+
+fn aplicar(tx: &Transaction, bloque: &Bloque, mensaje: &Mensaje, suceso: Suceso) -> rusqlite::Result<()> {
+    let ahora = bloque.timestamp;
+    match suceso {
+        Suceso::UsuarioRegistrado(evento) => {
+            let (nombre, apellido, dni) = match mensaje {
+                Mensaje::RegistrarUsuario { nombre, apellido, dni } => (Some(nombre), Some(apellido), Some(dni)),
+                _ => (None, None, None),
+            };
+            tx.execute(
+                "INSERT OR REPLACE INTO usuarios (id, cuenta, nombre, apellido, dni, bloque, registrado_en) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![evento.id_usuario, hex(evento.cuenta.as_ref()), nombre, apellido, dni, bloque.numero, ahora],
+            )?;
+        }
+        Suceso::EleccionCreada(evento) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO elecciones (id, cargo, inicio, fin, creada_en) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![evento.id_eleccion, evento.cargo, evento.inicio, evento.fin, ahora],
+            )?;
+        }
+        Suceso::EleccionEliminada(evento) => {
+            //el contrato saca la eleccion del vector, las siguientes bajan un id.
+            tx.execute("DELETE FROM elecciones WHERE id = ?1", params![evento.id_eleccion])?;
+            for tabla in TABLAS_POR_ELECCION {
+                tx.execute(&format!("DELETE FROM {tabla} WHERE id_eleccion = ?1"), params![evento.id_eleccion])?;
+            }
+            //en dos pasos para no chocar con la clave primaria mientras se renumera.
+            tx.execute("UPDATE elecciones SET id = -(id - 1) WHERE id > ?1", params![evento.id_eleccion])?;
+            tx.execute("UPDATE elecciones SET id = -id WHERE id < 0", [])?;
+            for tabla in TABLAS_POR_ELECCION {
+                tx.execute(&format!("UPDATE {tabla} SET id_eleccion = -(id_eleccion - 1) WHERE id_eleccion > ?1"), params![evento.id_eleccion])?;
+                tx.execute(&format!("UPDATE {tabla} SET id_eleccion = -id_eleccion WHERE id_eleccion < 0"), [])?;
+            }
+        }
+        Suceso::UsuarioPostulado(evento) => {
+            //una postulacion repetida no pisa la que ya fue resuelta.
+            let (rol, id_cargo) = if evento.es_votante { ("votante", None) } else { ("candidato", Some(evento.id_cargo)) };
+            tx.execute(
+                "INSERT INTO postulaciones (id_eleccion, id_usuario, rol, id_cargo, estado, postulado_en) VALUES (?1, ?2, ?3, ?4, 'pendiente', ?5)
+                 ON CONFLICT (id_eleccion, id_usuario) DO NOTHING",
+                params![evento.id_eleccion, evento.id_usuario, rol, id_cargo, ahora],
+            )?;
+        }
+        Suceso::PostulacionResuelta(evento) => {
+            let estado = if evento.aprobada { "aprobada" } else { "rechazada" };
+            tx.execute(
+                "UPDATE postulaciones SET estado = ?3, resuelta_en = ?4 WHERE id_eleccion = ?1 AND id_usuario = ?2",
+                params![evento.id_eleccion, evento.id_usuario, estado, ahora],
+            )?;
+        }
+        Suceso::VotoEmitido(evento) => {
+            let (tipo, objetivos) = match mensaje {
+                Mensaje::Votar { tipo, objetivos } if objetivos.is_empty() => (tipo.nombre(), vec![0]),
+                Mensaje::Votar { tipo, objetivos } => (tipo.nombre(), objetivos.clone()),
+                _ => ("desconocido", Vec::new()),
+            };
+            tx.execute(
+                "INSERT OR IGNORE INTO votos (comprobante, id_eleccion, tipo, bloque, emitido_en) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![hex(evento.comprobante.as_ref()), evento.id_eleccion, tipo, bloque.numero, ahora],
+            )?;
+            for objetivo in objetivos {
+                tx.execute(
+                    "INSERT INTO conteo_de_votos (id_eleccion, tipo, objetivo, votos) VALUES (?1, ?2, ?3, 1)
+                     ON CONFLICT (id_eleccion, tipo, objetivo) DO UPDATE SET votos = votos + 1",
+                    params![evento.id_eleccion, tipo, objetivo],
+                )?;
+            }
+        }
+        Suceso::EleccionFinalizada(evento) => {
+            tx.execute(
+                "UPDATE elecciones SET finalizada_en = ?2, hash_del_resultado = ?3 WHERE id = ?1",
+                params![evento.id_eleccion, ahora, hex(evento.hash_del_resultado.as_ref())],
+            )?;
+        }
+        Suceso::ReporteSolicitado(evento) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO reportes (cuenta, estado, actualizado_en) VALUES (?1, 'pendiente', ?2)",
+                params![hex(evento.cuenta.as_ref()), ahora],
+            )?;
+        }
+        Suceso::ReporteResuelto(evento) => {
+            let estado = if evento.aprobado { "aprobado" } else { "rechazado" };
+            tx.execute(
+                "UPDATE reportes SET estado = ?2, actualizado_en = ?3 WHERE cuenta = ?1",
+                params![hex(evento.cuenta.as_ref()), estado, ahora],
+            )?;
+        }
+        Suceso::Otro => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bloque::{leer_grabacion, Llamada};
+    use crate::decodificar::{evento, llamada};
+    use sistema_de_votacion::sistema_de_votacion::{
+        EleccionCreada, EleccionEliminada, PostulacionResuelta, UsuarioPostulado, UsuarioRegistrado, VotoEmitido,
+    };
+
+    const SISTEMA: Cuenta = [0x5a; 32];
+    const HORA: u64 = MILISEGUNDOS_POR_HORA;
+
+    fn bloque(numero: u64, timestamp: u64, llamadas: Vec<Llamada>) -> Bloque {
+        Bloque { numero, timestamp, llamadas }
+    }
+
+    fn llamada_con(datos: Vec<u8>, eventos: Vec<crate::bloque::Evento>) -> Llamada {
+        Llamada { contrato: SISTEMA, datos, exito: true, eventos }
+    }
+
+    fn votar(id_eleccion: i16, id_candidato: i16, comprobante: u8) -> Llamada {
+        llamada_con(
+            llamada("votar_a_candidato", (1i16, id_eleccion, id_candidato, [0u8; 32])),
+            vec![evento(SISTEMA, &VotoEmitido { id_eleccion, comprobante: [comprobante; 32].into() })],
+        )
+    }
+
+    fn eleccion(id_eleccion: i16, cargo: &str) -> Llamada {
+        let creada = EleccionCreada { id_eleccion, cargo: String::from(cargo), inicio: 0, fin: 10 * HORA as i64 };
+        llamada_con(llamada("crear_eleccion", ()), vec![evento(SISTEMA, &creada)])
+    }
+
+    #[test]
+    fn indexa_usuarios_postulaciones_y_votos() {
+        let mut base = Base::en_memoria().unwrap();
+        let registro = llamada_con(
+            llamada("registrar_usuario", (String::from("Carlos"), String::from("Sanchez"), String::from("7654456"))),
+            vec![evento(SISTEMA, &UsuarioRegistrado { id_usuario: 1, cuenta: [0x01; 32].into() })],
+        );
+        let postulacion = llamada_con(
+            llamada("postulacion_de_usuario", ()),
+            vec![evento(SISTEMA, &UsuarioPostulado { id_eleccion: 1, id_usuario: 1, es_votante: true, id_cargo: 1 })],
+        );
+        let validacion = llamada_con(
+            llamada("validar_usuario", ()),
+            vec![evento(SISTEMA, &PostulacionResuelta { id_eleccion: 1, id_usuario: 1, aprobada: true })],
+        );
+        assert_eq!(base.indexar(&bloque(1, 100, vec![eleccion(1, "Presidente"), registro, postulacion]), &SISTEMA), Ok(true));
+        assert_eq!(base.indexar(&bloque(2, 200, vec![validacion]), &SISTEMA), Ok(true));
+        let repetida = llamada_con(
+            llamada("postulacion_de_usuario", ()),
+            vec![evento(SISTEMA, &UsuarioPostulado { id_eleccion: 1, id_usuario: 1, es_votante: true, id_cargo: 1 })],
+        );
+        assert_eq!(base.indexar(&bloque(3, 250, vec![repetida]), &SISTEMA), Ok(true));
+        let mut fallida = votar(1, 3, 0x09);
+        fallida.exito = false;
+        let bloque_de_votos = bloque(4, 2 * HORA + 5, vec![votar(1, 3, 0x01), votar(1, 3, 0x02), fallida]);
+        assert_eq!(base.indexar(&bloque_de_votos, &SISTEMA), Ok(true));
+        assert_eq!(base.indexar(&bloque(5, 3 * HORA, vec![votar(1, 2, 0x03)]), &SISTEMA), Ok(true));
+        assert_eq!(base.indexar(&bloque_de_votos, &SISTEMA), Ok(false)); //ya indexado
+
+        assert_eq!(base.ultimo_bloque(), Ok(Some(5)));
+        assert_eq!(base.votos_por_hora(1), Ok(vec![(2 * HORA, 2), (3 * HORA, 1)]));
+        assert_eq!(base.conteo_de_votos(1), Ok(vec![(String::from("candidato"), 2, 1), (String::from("candidato"), 3, 2)]));
+        assert_eq!(
+            base.postulaciones_de_usuario(1),
+            Ok(vec![Postulacion {
+                id_eleccion: 1,
+                rol: String::from("votante"),
+                estado: String::from("aprobada"),
+                postulado_en: 100,
+                resuelta_en: Some(200),
+            }])
+        );
+        let nombre: String = base.conexion.query_row("SELECT nombre FROM usuarios WHERE id = 1", [], |f| f.get(0)).unwrap();
+        assert_eq!(nombre, "Carlos");
+    }
+
+    #[test]
+    fn ignora_otros_contratos() {
+        let mut base = Base::en_memoria().unwrap();
+        let mut ajena = votar(1, 1, 0x01);
+        ajena.contrato = [0x01; 32];
+        ajena.eventos[0].contrato = [0x01; 32];
+        base.indexar(&bloque(1, HORA, vec![eleccion(1, "Presidente"), ajena]), &SISTEMA).unwrap();
+        assert_eq!(base.votos_por_hora(1), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn renumera_al_eliminar_una_eleccion() {
+        let mut base = Base::en_memoria().unwrap();
+        let eliminar = llamada_con(Vec::from([0; 4]), vec![evento(SISTEMA, &EleccionEliminada { id_eleccion: 1 })]);
+        let llamadas = vec![eleccion(1, "Presidente"), eleccion(2, "Tesorero"), eleccion(3, "Vocal"), votar(2, 1, 0x01), votar(3, 1, 0x02)];
+        base.indexar(&bloque(1, HORA, llamadas), &SISTEMA).unwrap();
+        base.indexar(&bloque(2, HORA, vec![eliminar]), &SISTEMA).unwrap();
+        let cargos: Vec<(i16, String)> = base
+            .conexion
+            .prepare("SELECT id, cargo FROM elecciones ORDER BY id")
+            .unwrap()
+            .query_map([], |f| Ok((f.get(0)?, f.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(cargos, vec![(1, String::from("Tesorero")), (2, String::from("Vocal"))]);
+        assert_eq!(base.votos_por_hora(1), Ok(vec![(HORA, 1)]));
+        assert_eq!(base.votos_por_hora(3), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn indexa_la_grabacion_de_ejemplo() {
+        let mut base = Base::en_memoria().unwrap();
+        let sistema = crate::bloque::fijo(&"5a".repeat(32)).unwrap();
+        for bloque in leer_grabacion(include_str!("../fixtures/ejemplo.jsonl")).unwrap() {
+            base.indexar(&bloque, &sistema).unwrap();
+        }
+        assert_eq!(base.votos_por_hora(1), Ok(vec![(1_728_990_000_000, 2), (1_728_993_600_000, 1)]));
+        let postulaciones = base.postulaciones_de_usuario(2).unwrap();
+        assert_eq!(postulaciones[0].estado, "rechazada");
+        let estado: String = base.conexion.query_row("SELECT estado FROM reportes", [], |f| f.get(0)).unwrap();
+        assert_eq!(estado, "aprobado");
+    }
+}
\ No newline at end of file
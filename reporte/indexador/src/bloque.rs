@@ -0,0 +1,215 @@
+//! Bloques tal como los procesa el indexador, vengan del nodo o de un archivo grabado.
+//!
+//! El archivo grabado tiene un bloque JSON por linea, los bytes van en hexadecimal con o sin `0x`:
+//! ```text
+//! {"numero":12,"timestamp":1728950400000,"llamadas":[{"contrato":"0x..","datos":"0x<selector><argumentos>",
+//!   "exito":true,"eventos":[{"contrato":"0x..","topicos":["0x.."],"datos":"0x.."}]}]}
+//! ```
+//! Las lineas vacias y las que empiezan con `#` se ignoran.
+
+use blake2::{Blake2b512, Digest};
+#[cfg(any(feature = "nodo", test))]
+use serde_json::json;
+use serde_json::Value;
+
+/// AccountId de 32 bytes de un contrato.
+pub type Cuenta = [u8; 32];
+
+/// Evento ContractEmitted de pallet-contracts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evento {
+    pub contrato: Cuenta,
+    pub topicos: Vec<[u8; 32]>,
+    pub datos: Vec<u8>,
+}
+
+/// Extrinsic Contracts::call con los eventos que emitio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Llamada {
+    pub contrato: Cuenta,
+    pub datos: Vec<u8>, //selector del mensaje seguido de sus argumentos.
+    pub exito: bool,
+    pub eventos: Vec<Evento>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bloque {
+    pub numero: u64,
+    pub timestamp: u64, //milisegundos, igual que block_timestamp en el contrato.
+    pub llamadas: Vec<Llamada>,
+}
+
+impl Bloque {
+    pub fn desde_json(linea: &str) -> Result<Bloque, String> {
+        let json: Value = serde_json::from_str(linea).map_err(|e| format!("El bloque no es un JSON valido: {e}"))?;
+        let numero = json["numero"].as_u64().ok_or_else(|| String::from("El bloque no tiene numero"))?;
+        let timestamp = json["timestamp"].as_u64().ok_or_else(|| String::from("El bloque no tiene timestamp"))?;
+        let mut llamadas = Vec::new();
+        for llamada in json["llamadas"].as_array().map(Vec::as_slice).unwrap_or_default() {
+            let mut eventos = Vec::new();
+            for evento in llamada["eventos"].as_array().map(Vec::as_slice).unwrap_or_default() {
+                let topicos = evento["topicos"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|t| fijo(&texto(t)?))
+                    .collect::<Result<Vec<_>, String>>()?;
+                eventos.push(Evento { contrato: fijo(&texto(&evento["contrato"])?)?, topicos, datos: desde_hex(&texto(&evento["datos"])?)? });
+            }
+            llamadas.push(Llamada {
+                contrato: fijo(&texto(&llamada["contrato"])?)?,
+                datos: desde_hex(&texto(&llamada["datos"])?)?,
+                exito: llamada["exito"].as_bool().unwrap_or(false),
+                eventos,
+            });
+        }
+        Ok(Bloque { numero, timestamp, llamadas })
+    }
+
+    /// Una linea del archivo grabado, es lo que escribe `indexador nodo --grabar`.
+    #[cfg(any(feature = "nodo", test))]
+    pub fn a_json(&self) -> String {
+        let llamadas: Vec<Value> = self
+            .llamadas
+            .iter()
+            .map(|llamada| {
+                let eventos: Vec<Value> = llamada
+                    .eventos
+                    .iter()
+                    .map(|evento| {
+                        let topicos: Vec<String> = evento.topicos.iter().map(|t| hex(t)).collect();
+                        json!({ "contrato": hex(&evento.contrato), "topicos": topicos, "datos": hex(&evento.datos) })
+                    })
+                    .collect();
+                json!({ "contrato": hex(&llamada.contrato), "datos": hex(&llamada.datos), "exito": llamada.exito, "eventos": eventos })
+            })
+            .collect();
+        json!({ "numero": self.numero, "timestamp": self.timestamp, "llamadas": llamadas }).to_string()
+    }
+}
+
+/// Lee todos los bloques del archivo grabado, en el orden del archivo.
+pub fn leer_grabacion(contenido: &str) -> Result<Vec<Bloque>, String> {
+    let mut bloques = Vec::new();
+    for (i, linea) in contenido.lines().enumerate() {
+        let linea = linea.trim();
+        if linea.is_empty() || linea.starts_with('#') {
+            continue;
+        }
+        bloques.push(Bloque::desde_json(linea).map_err(|e| format!("Linea {}: {e}", i + 1))?);
+    }
+    Ok(bloques)
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    let digitos: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!("0x{digitos}")
+}
+
+pub fn desde_hex(texto: &str) -> Result<Vec<u8>, String> {
+    let digitos = texto.trim().trim_start_matches("0x");
+    if !digitos.len().is_multiple_of(2) || !digitos.is_ascii() {
+        return Err(format!("Hexadecimal invalido '{texto}'"));
+    }
+    (0..digitos.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digitos[i..i + 2], 16).map_err(|_| format!("Hexadecimal invalido '{texto}'")))
+        .collect()
+}
+
+/// Cuenta o topico de 32 bytes escrito en hexadecimal.
+pub fn fijo(texto: &str) -> Result<[u8; 32], String> {
+    desde_hex(texto)?.try_into().map_err(|_| format!("Se esperaban 32 bytes en '{texto}'"))
+}
+
+/// Cuenta escrita en SS58, con cualquier prefijo de red. Verifica la suma de control.
+pub fn ss58(texto: &str) -> Result<Cuenta, String> {
+    const ALFABETO: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    let invalida = || format!("Direccion invalida '{texto}'");
+    let mut bytes: Vec<u8> = Vec::new(); //en little endian mientras se decodifica.
+    for caracter in texto.bytes() {
+        let mut acarreo = ALFABETO.iter().position(|&c| c == caracter).ok_or_else(invalida)? as u32;
+        for byte in bytes.iter_mut() {
+            acarreo += *byte as u32 * 58;
+            *byte = acarreo as u8;
+            acarreo >>= 8;
+        }
+        while acarreo > 0 {
+            bytes.push(acarreo as u8);
+            acarreo >>= 8;
+        }
+    }
+    bytes.extend(texto.bytes().take_while(|&c| c == b'1').map(|_| 0));
+    bytes.reverse();
+    let prefijo = match bytes.first() {
+        Some(0..=63) => 1,
+        Some(64..=127) => 2,
+        _ => return Err(invalida()),
+    };
+    if bytes.len() != prefijo + 32 + 2 {
+        return Err(invalida());
+    }
+    let (contenido, suma) = bytes.split_at(prefijo + 32);
+    let hash = Blake2b512::new().chain_update(b"SS58PRE").chain_update(contenido).finalize();
+    if hash[..2] != *suma {
+        return Err(format!("La suma de control de '{texto}' no coincide"));
+    }
+    contenido[prefijo..].try_into().map_err(|_| invalida())
+}
+
+fn texto(valor: &Value) -> Result<String, String> {
+    valor.as_str().map(String::from).ok_or_else(|| format!("Se esperaba un texto hexadecimal y se encontro {valor}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bloque() -> Bloque {
+        Bloque {
+            numero: 7,
+            timestamp: 1_728_950_400_000,
+            llamadas: vec![Llamada {
+                contrato: [0x5a; 32],
+                datos: vec![0xde, 0xad, 0xbe, 0xef, 0x01],
+                exito: true,
+                eventos: vec![Evento { contrato: [0x5a; 32], topicos: vec![[0x01; 32], [0x02; 32]], datos: vec![0x04, 0x00] }],
+            }],
+        }
+    }
+
+    #[test]
+    fn ida_y_vuelta_por_json() {
+        let original = bloque();
+        assert_eq!(Bloque::desde_json(&original.a_json()), Ok(original.clone()));
+        let grabacion = format!("# grabado de prueba\n\n{}\n", original.a_json());
+        assert_eq!(leer_grabacion(&grabacion), Ok(vec![original]));
+    }
+
+    #[test]
+    fn convierte_hexadecimal() {
+        assert_eq!(hex(&[0x00, 0xff, 0x10]), "0x00ff10");
+        assert_eq!(desde_hex("0x00ff10"), Ok(vec![0x00, 0xff, 0x10]));
+        assert_eq!(desde_hex("00FF10"), Ok(vec![0x00, 0xff, 0x10]));
+        assert!(desde_hex("0x0").is_err());
+        assert!(desde_hex("0xzz").is_err());
+        assert!(fijo("0x0102").is_err());
+    }
+
+    #[test]
+    fn convierte_ss58() {
+        let alice = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+        assert_eq!(ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").map(|c| hex(&c)), Ok(String::from(alice)));
+        assert_eq!(ss58("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").map(|c| hex(&c)), Ok(String::from(alice))); //polkadot
+        assert!(ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ").is_err());
+        assert!(ss58("0OIl").is_err());
+        assert!(ss58("").is_err());
+    }
+
+    #[test]
+    fn informa_la_linea_con_error() {
+        let grabacion = format!("{}\n{{\"numero\":8}}", bloque().a_json());
+        assert_eq!(leer_grabacion(&grabacion), Err(String::from("Linea 2: El bloque no tiene timestamp")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+//! Decodificacion de las llamadas y eventos del SistemaDeVotacion con los tipos SCALE del contrato.
+//!
+//! Los eventos dicen que cambio (ids de usuario y eleccion, aprobaciones, comprobantes) y las llamadas
+//! completan lo que los eventos no traen: los datos del usuario registrado y a quien se voto.
+
+use ink::env::Event;
+use scale::Decode;
+use sistema_de_votacion::sistema_de_votacion::{
+    EleccionCreada, EleccionEliminada, EleccionFinalizada, PostulacionResuelta, ReporteResuelto, ReporteSolicitado,
+    UsuarioPostulado, UsuarioRegistrado, VotoEmitido,
+};
+
+use crate::bloque::Evento;
+
+const REGISTRAR_USUARIO: [u8; 4] = ink::selector_bytes!("registrar_usuario");
+const VOTAR_A_CANDIDATO: [u8; 4] = ink::selector_bytes!("votar_a_candidato");
+const VOTAR_BOLETA: [u8; 4] = ink::selector_bytes!("votar_boleta");
+const VOTAR_OPCION: [u8; 4] = ink::selector_bytes!("votar_opcion");
+const VOTAR_A_LISTA: [u8; 4] = ink::selector_bytes!("votar_a_lista");
+const VOTAR_EN_BLANCO: [u8; 4] = ink::selector_bytes!("votar_en_blanco");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipoDeVoto {
+    Candidato,
+    Boleta,
+    Opcion,
+    Lista,
+    Blanco,
+}
+
+impl TipoDeVoto {
+    pub fn nombre(&self) -> &'static str {
+        match self {
+            TipoDeVoto::Candidato => "candidato",
+            TipoDeVoto::Boleta => "boleta",
+            TipoDeVoto::Opcion => "opcion",
+            TipoDeVoto::Lista => "lista",
+            TipoDeVoto::Blanco => "blanco",
+        }
+    }
+}
+
+/// Mensaje llamado, solo los que el indexador necesita leer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mensaje {
+    RegistrarUsuario { nombre: String, apellido: String, dni: String },
+    /// objetivos son los ids votados: candidato, opcion o lista, los numeros de boleta o ninguno si es en blanco.
+    Votar { tipo: TipoDeVoto, objetivos: Vec<i16> },
+    Otro,
+}
+
+/// Evento del sistema ya decodificado.
+pub enum Suceso {
+    UsuarioRegistrado(UsuarioRegistrado),
+    EleccionCreada(EleccionCreada),
+    EleccionEliminada(EleccionEliminada),
+    UsuarioPostulado(UsuarioPostulado),
+    PostulacionResuelta(PostulacionResuelta),
+    VotoEmitido(VotoEmitido),
+    EleccionFinalizada(EleccionFinalizada),
+    ReporteSolicitado(ReporteSolicitado),
+    ReporteResuelto(ReporteResuelto),
+    Otro, //pausas y eventos que el indexador no guarda.
+}
+
+pub fn decodificar_llamada(datos: &[u8]) -> Result<Mensaje, String> {
+    if datos.len() < 4 {
+        return Err(String::from("La llamada no tiene selector"));
+    }
+    let (selector, mut argumentos) = datos.split_at(4);
+    let error = |e: scale::Error| format!("No se pudieron decodificar los argumentos: {e}");
+    let mensaje = match <[u8; 4]>::try_from(selector).unwrap() {
+        REGISTRAR_USUARIO => {
+            let (nombre, apellido, dni) = <(String, String, String)>::decode(&mut argumentos).map_err(error)?;
+            Mensaje::RegistrarUsuario { nombre, apellido, dni }
+        }
+        VOTAR_A_CANDIDATO | VOTAR_OPCION | VOTAR_A_LISTA => {
+            let (_, _, objetivo) = <(i16, i16, i16)>::decode(&mut argumentos).map_err(error)?;
+            let tipo = match <[u8; 4]>::try_from(selector).unwrap() {
+                VOTAR_A_CANDIDATO => TipoDeVoto::Candidato,
+                VOTAR_OPCION => TipoDeVoto::Opcion,
+                _ => TipoDeVoto::Lista,
+            };
+            Mensaje::Votar { tipo, objetivos: vec![objetivo] }
+        }
+        VOTAR_BOLETA => {
+            let (_, _, numeros) = <(i16, i16, Vec<i16>)>::decode(&mut argumentos).map_err(error)?;
+            Mensaje::Votar { tipo: TipoDeVoto::Boleta, objetivos: numeros }
+        }
+        VOTAR_EN_BLANCO => {
+            <(i16, i16)>::decode(&mut argumentos).map_err(error)?;
+            Mensaje::Votar { tipo: TipoDeVoto::Blanco, objetivos: Vec::new() }
+        }
+        _ => Mensaje::Otro,
+    };
+    Ok(mensaje)
+}
+
+/// Identifica el evento por su primer topico, la firma que calcula ink!, y decodifica sus datos.
+pub fn decodificar_evento(evento: &Evento) -> Result<Suceso, String> {
+    let firma = match evento.topicos.first() {
+        Some(firma) => Some(*firma),
+        None => return Ok(Suceso::Otro),
+    };
+    let datos = &mut evento.datos.as_slice();
+    let error = |e: scale::Error| format!("No se pudo decodificar el evento: {e}");
+    let suceso = if firma == UsuarioRegistrado::SIGNATURE_TOPIC {
+        Suceso::UsuarioRegistrado(Decode::decode(datos).map_err(error)?)
+    } else if firma == EleccionCreada::SIGNATURE_TOPIC {
+        Suceso::EleccionCreada(Decode::decode(datos).map_err(error)?)
+    } else if firma == EleccionEliminada::SIGNATURE_TOPIC {
+        Suceso::EleccionEliminada(Decode::decode(datos).map_err(error)?)
+    } else if firma == UsuarioPostulado::SIGNATURE_TOPIC {
+        Suceso::UsuarioPostulado(Decode::decode(datos).map_err(error)?)
+    } else if firma == PostulacionResuelta::SIGNATURE_TOPIC {
+        Suceso::PostulacionResuelta(Decode::decode(datos).map_err(error)?)
+    } else if firma == VotoEmitido::SIGNATURE_TOPIC {
+        Suceso::VotoEmitido(Decode::decode(datos).map_err(error)?)
+    } else if firma == EleccionFinalizada::SIGNATURE_TOPIC {
+        Suceso::EleccionFinalizada(Decode::decode(datos).map_err(error)?)
+    } else if firma == ReporteSolicitado::SIGNATURE_TOPIC {
+        Suceso::ReporteSolicitado(Decode::decode(datos).map_err(error)?)
+    } else if firma == ReporteResuelto::SIGNATURE_TOPIC {
+        Suceso::ReporteResuelto(Decode::decode(datos).map_err(error)?)
+    } else {
+        Suceso::Otro
+    };
+    Ok(suceso)
+}
+
+#[cfg(test)]
+/// Datos de una llamada al mensaje: selector seguido de los argumentos codificados.
+pub fn llamada<A: scale::Encode>(mensaje: &str, argumentos: A) -> Vec<u8> {
+    let selector = match mensaje {
+        "registrar_usuario" => REGISTRAR_USUARIO,
+        "votar_a_candidato" => VOTAR_A_CANDIDATO,
+        "votar_boleta" => VOTAR_BOLETA,
+        "votar_opcion" => VOTAR_OPCION,
+        "votar_a_lista" => VOTAR_A_LISTA,
+        "votar_en_blanco" => VOTAR_EN_BLANCO,
+        _ => [0; 4],
+    };
+    let mut datos = selector.to_vec();
+    datos.extend(argumentos.encode());
+    datos
+}
+
+#[cfg(test)]
+/// Evento como lo emite el contrato: la firma como primer topico y los campos codificados.
+pub fn evento<E: Event>(contrato: [u8; 32], valor: &E) -> Evento {
+    Evento { contrato, topicos: E::SIGNATURE_TOPIC.into_iter().collect(), datos: valor.encode() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodifica_llamadas() {
+        let datos = llamada("registrar_usuario", (String::from("Carlos"), String::from("Sanchez"), String::from("7654456")));
+        assert_eq!(
+            decodificar_llamada(&datos),
+            Ok(Mensaje::RegistrarUsuario { nombre: String::from("Carlos"), apellido: String::from("Sanchez"), dni: String::from("7654456") })
+        );
+        let datos = llamada("votar_boleta", (1i16, 2i16, vec![3i16, 5], [0u8; 32]));
+        assert_eq!(decodificar_llamada(&datos), Ok(Mensaje::Votar { tipo: TipoDeVoto::Boleta, objetivos: vec![3, 5] }));
+        let datos = llamada("votar_a_lista", (1i16, 2i16, 4i16, [0u8; 32]));
+        assert_eq!(decodificar_llamada(&datos), Ok(Mensaje::Votar { tipo: TipoDeVoto::Lista, objetivos: vec![4] }));
+        assert_eq!(decodificar_llamada(&llamada("crear_eleccion", ())), Ok(Mensaje::Otro));
+        assert!(decodificar_llamada(&llamada("votar_a_candidato", (1i16,))).is_err());
+        assert!(decodificar_llamada(&[0x01]).is_err());
+    }
+
+    #[test]
+    fn decodifica_eventos_por_firma() {
+        let voto = VotoEmitido { id_eleccion: 4, comprobante: [0x07; 32].into() };
+        match decodificar_evento(&evento([0x5a; 32], &voto)) {
+            Ok(Suceso::VotoEmitido(voto)) => {
+                assert_eq!(voto.id_eleccion, 4);
+                assert_eq!(voto.comprobante, [0x07; 32].into());
+            }
+            _ => panic!("se esperaba un VotoEmitido"),
+        }
+        let resuelta = PostulacionResuelta { id_eleccion: 1, id_usuario: 17, aprobada: true };
+        assert!(matches!(decodificar_evento(&evento([0x5a; 32], &resuelta)), Ok(Suceso::PostulacionResuelta(p)) if p.id_usuario == 17));
+
+        let sin_topicos = Evento { contrato: [0x5a; 32], topicos: Vec::new(), datos: Vec::new() };
+        assert!(matches!(decodificar_evento(&sin_topicos), Ok(Suceso::Otro)));
+        let mut truncado = evento([0x5a; 32], &voto);
+        truncado.datos.truncate(3);
+        assert!(decodificar_evento(&truncado).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,176 @@
+//! Indexador fuera de la cadena del sistema de votacion.
+//!
+//! Sigue la actividad del contrato SistemaDeVotacion (incluidos los pedidos de permiso de los
+//! contratos Reporte) desde un nodo o desde una grabacion de bloques, y la guarda en una base
+//! SQLite para responder consultas historicas que el contrato no puede responder barato.
+//! El comando `nodo` necesita compilar con `--features nodo`, el resto solo usa std y SQLite.
+//!
+//! EJEMPLO:
+//! ```text
+//! indexador --base votacion.db nodo --sistema 5F... --grabar bloques.jsonl
+//! indexador --base votacion.db grabacion --sistema 5F... --archivo bloques.jsonl
+//! indexador --base votacion.db votos-por-hora --eleccion 4
+//! indexador --base votacion.db usuario --id 17
+//! ```
+
+mod base;
+mod bloque;
+mod decodificar;
+#[cfg(feature = "nodo")]
+mod nodo;
+
+use std::fs;
+#[cfg(feature = "nodo")]
+use std::fs::OpenOptions;
+#[cfg(feature = "nodo")]
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use base::Base;
+use bloque::Cuenta;
+
+#[derive(Parser)]
+#[command(name = "indexador", about = "Indexa la actividad del sistema de votacion en SQLite")]
+struct Cli {
+    /// Archivo de la base SQLite, se crea si no existe.
+    #[arg(long, env = "VOTACION_BASE", default_value = "votacion.db")]
+    base: PathBuf,
+    #[command(subcommand)]
+    comando: Comando,
+}
+
+#[derive(Subcommand)]
+enum Comando {
+    /// Sigue los bloques finalizados del nodo desde el ultimo indexado.
+    #[cfg(feature = "nodo")]
+    Nodo {
+        #[arg(long, env = "VOTACION_URL", default_value = "ws://127.0.0.1:9944")]
+        url: String,
+        /// Direccion del contrato SistemaDeVotacion, en SS58 o hexadecimal.
+        #[arg(long, env = "VOTACION_CONTRATO")]
+        sistema: String,
+        /// Agrega cada bloque leido a este archivo, para volver a indexarlo sin el nodo.
+        #[arg(long)]
+        grabar: Option<PathBuf>,
+    },
+    /// Indexa una grabacion de bloques, un bloque JSON por linea.
+    Grabacion {
+        #[arg(long, env = "VOTACION_CONTRATO")]
+        sistema: String,
+        #[arg(long)]
+        archivo: PathBuf,
+    },
+    /// Votos emitidos por hora en una eleccion.
+    VotosPorHora {
+        #[arg(long)]
+        eleccion: i16,
+    },
+    /// Votos por candidato, boleta, opcion o lista en una eleccion.
+    Conteo {
+        #[arg(long)]
+        eleccion: i16,
+    },
+    /// Postulaciones de un usuario y cuando se validaron.
+    Usuario {
+        #[arg(long)]
+        id: i16,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match ejecutar(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn ejecutar(cli: Cli) -> Result<(), String> {
+    let mut base = Base::abrir(&cli.base)?;
+    match cli.comando {
+        #[cfg(feature = "nodo")]
+        Comando::Nodo { url, sistema, grabar } => {
+            let sistema = cuenta(&sistema)?;
+            let mut grabacion = match grabar {
+                Some(ruta) => Some(
+                    OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&ruta)
+                        .map_err(|e| format!("No se pudo abrir {}: {e}", ruta.display()))?,
+                ),
+                None => None,
+            };
+            let desde = base.ultimo_bloque()?.map_or(0, |ultimo| ultimo + 1);
+            let procesar = |bloque: bloque::Bloque| {
+                if let Some(archivo) = grabacion.as_mut() {
+                    writeln!(archivo, "{}", bloque.a_json()).map_err(|e| format!("No se pudo grabar el bloque: {e}"))?;
+                }
+                if base.indexar(&bloque, &sistema)? {
+                    println!("Bloque {} indexado", bloque.numero);
+                }
+                Ok(())
+            };
+            let runtime = tokio::runtime::Runtime::new().map_err(|e| format!("No se pudo iniciar tokio: {e}"))?;
+            runtime.block_on(nodo::seguir(&url, desde, procesar))?;
+        }
+        Comando::Grabacion { sistema, archivo } => {
+            let sistema = cuenta(&sistema)?;
+            let contenido = fs::read_to_string(&archivo).map_err(|e| format!("No se pudo leer {}: {e}", archivo.display()))?;
+            let mut indexados = 0;
+            for bloque in bloque::leer_grabacion(&contenido)? {
+                if base.indexar(&bloque, &sistema)? {
+                    indexados += 1;
+                }
+            }
+            println!("{indexados} bloques indexados, ultimo bloque {:?}", base.ultimo_bloque()?);
+        }
+        Comando::VotosPorHora { eleccion } => {
+            for (hora, votos) in base.votos_por_hora(eleccion)? {
+                println!("{hora}\t{votos}");
+            }
+        }
+        Comando::Conteo { eleccion } => {
+            for (tipo, objetivo, votos) in base.conteo_de_votos(eleccion)? {
+                println!("{tipo}\t{objetivo}\t{votos}");
+            }
+        }
+        Comando::Usuario { id } => {
+            for postulacion in base.postulaciones_de_usuario(id)? {
+                let resuelta = postulacion.resuelta_en.map_or(String::from("-"), |t| t.to_string());
+                println!(
+                    "eleccion {}\t{}\t{}\tpostulado {}\tresuelta {resuelta}",
+                    postulacion.id_eleccion, postulacion.rol, postulacion.estado, postulacion.postulado_en
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Direccion del contrato en SS58 o en hexadecimal de 32 bytes.
+fn cuenta(texto: &str) -> Result<Cuenta, String> {
+    if texto.starts_with("0x") {
+        return bloque::fijo(texto);
+    }
+    bloque::ss58(texto)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acepta_ss58_y_hexadecimal() {
+        let alice = cuenta("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+        assert_eq!(bloque::hex(&alice), "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+        assert_eq!(cuenta(&bloque::hex(&alice)), Ok(alice));
+        assert!(cuenta("no es una cuenta").is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+//! Seguimiento de un nodo de substrate con pallet-contracts a traves de subxt.
+//!
+//! Se leen los bloques finalizados: de cada uno se toma el timestamp (Timestamp::set) y las llamadas
+//! Contracts::call con sus eventos ContractEmitted. Si el indexador estuvo apagado, primero se
+//! recorren los bloques que faltan desde el ultimo guardado.
+
+use scale::{Compact, Decode};
+use subxt::backend::legacy::rpc_methods::NumberOrHex;
+use subxt::backend::legacy::LegacyRpcMethods;
+use subxt::backend::rpc::RpcClient;
+use subxt::blocks::Block;
+use subxt::{OnlineClient, SubstrateConfig};
+
+use crate::bloque::{Bloque, Cuenta, Evento, Llamada};
+
+type Cliente = OnlineClient<SubstrateConfig>;
+
+fn error(e: subxt::Error) -> String {
+    format!("Error del nodo: {e}")
+}
+
+/// Llama a procesar con cada bloque finalizado a partir de desde, en orden. No termina salvo error.
+pub async fn seguir(url: &str, desde: u64, mut procesar: impl FnMut(Bloque) -> Result<(), String>) -> Result<(), String> {
+    let rpc = RpcClient::from_url(url).await.map_err(error)?;
+    let metodos = LegacyRpcMethods::<SubstrateConfig>::new(rpc.clone());
+    let api = Cliente::from_rpc_client(rpc).await.map_err(error)?;
+    let mut finalizados = api.blocks().subscribe_finalized().await.map_err(error)?;
+    let mut siguiente = desde;
+    while let Some(bloque) = finalizados.next().await {
+        let bloque = bloque.map_err(error)?;
+        let numero = u64::from(bloque.number());
+        while siguiente < numero {
+            let hash = metodos
+                .chain_get_block_hash(Some(NumberOrHex::Number(siguiente)))
+                .await
+                .map_err(error)?
+                .ok_or_else(|| format!("El nodo no tiene el bloque {siguiente}"))?;
+            procesar(convertir(&api.blocks().at(hash).await.map_err(error)?).await?)?;
+            siguiente += 1;
+        }
+        if numero >= siguiente {
+            procesar(convertir(&bloque).await?)?;
+            siguiente = numero + 1;
+        }
+    }
+    Err(String::from("El nodo cerro la suscripcion de bloques finalizados"))
+}
+
+async fn convertir(bloque: &Block<SubstrateConfig, Cliente>) -> Result<Bloque, String> {
+    let mut timestamp = 0;
+    let mut llamadas = Vec::new();
+    for extrinsic in bloque.extrinsics().await.map_err(error)?.iter() {
+        let extrinsic = extrinsic.map_err(error)?;
+        match (extrinsic.pallet_name().map_err(error)?, extrinsic.variant_name().map_err(error)?) {
+            ("Timestamp", "set") => {
+                timestamp = Compact::<u64>::decode(&mut extrinsic.field_bytes())
+                    .map_err(|e| format!("Timestamp invalido: {e}"))?
+                    .0;
+            }
+            ("Contracts", "call") => {
+                let (contrato, datos) = argumentos_de_call(extrinsic.field_bytes())?;
+                let mut exito = false;
+                let mut eventos = Vec::new();
+                for evento in extrinsic.events().await.map_err(error)?.iter() {
+                    let evento = evento.map_err(error)?;
+                    match (evento.pallet_name(), evento.variant_name()) {
+                        ("System", "ExtrinsicSuccess") => exito = true,
+                        ("Contracts", "ContractEmitted") => {
+                            let (contrato, datos) = <(Cuenta, Vec<u8>)>::decode(&mut evento.field_bytes())
+                                .map_err(|e| format!("ContractEmitted invalido: {e}"))?;
+                            let topicos = evento.topics().iter().map(|topico| topico.0).collect();
+                            eventos.push(Evento { contrato, topicos, datos });
+                        }
+                        _ => {}
+                    }
+                }
+                llamadas.push(Llamada { contrato, datos, exito, eventos });
+            }
+            _ => {}
+        }
+    }
+    Ok(Bloque { numero: u64::from(bloque.number()), timestamp, llamadas })
+}
+
+/// Contrato y datos de Contracts::call(dest, value, gas_limit, storage_deposit_limit, data).
+fn argumentos_de_call(mut bytes: &[u8]) -> Result<(Cuenta, Vec<u8>), String> {
+    let error = |e: scale::Error| format!("Contracts::call invalido: {e}");
+    let bytes = &mut bytes;
+    if u8::decode(bytes).map_err(error)? != 0 {
+        return Err(String::from("Contracts::call con un destino que no es MultiAddress::Id"));
+    }
+    let contrato = Cuenta::decode(bytes).map_err(error)?;
+    Compact::<u128>::decode(bytes).map_err(error)?; //value
+    <(Compact<u64>, Compact<u64>)>::decode(bytes).map_err(error)?; //gas_limit
+    Option::<Compact<u128>>::decode(bytes).map_err(error)?; //storage_deposit_limit
+    let datos = Vec::<u8>::decode(bytes).map_err(error)?;
+    Ok((contrato, datos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::Encode;
+
+    #[test]
+    fn lee_destino_y_datos_de_la_llamada() {
+        let mut bytes = vec![0u8];
+        bytes.extend([0x5a; 32]);
+        bytes.extend(Compact(0u128).encode());
+        bytes.extend((Compact(5_000_000_000u64), Compact(200_000u64)).encode());
+        bytes.extend(Some(Compact(1_000u128)).encode());
+        bytes.extend(vec![0xdeu8, 0xad, 0xbe, 0xef].encode());
+        assert_eq!(argumentos_de_call(&bytes), Ok(([0x5a; 32], vec![0xde, 0xad, 0xbe, 0xef])));
+        bytes[0] = 2;
+        assert!(argumentos_de_call(&bytes).is_err());
+        assert!(argumentos_de_call(&[0u8, 1, 2]).is_err());
+    }
+}
\ No newline at end of file
@@ -1,36 +1,66 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+/// Conversion de los reportes a texto CSV o JSON, para abrirlos en una planilla o publicarlos en la web.
+pub mod exportar;
+
 #[ink::contract]
 mod reporte {
     use ink::prelude::vec::Vec;  
     use ink::prelude::string::String; 
+    use ink::prelude::vec;
+    use core::cmp::Reverse;
+    use ink::storage::Mapping;
     use sistema_de_votacion::SistemaDeVotacionRef;
+    use sistema_de_votacion::sistema_de_votacion::EstadoDeReporte;
+    use crate::exportar::{porcentaje, Formato, Tabla, Valor, COLUMNAS_DE_ELECCION, COLUMNAS_DE_PARTICIPACION, COLUMNAS_DE_RESULTADO};
 
-    #[derive(scale::Decode, scale::Encode,Debug,Default,Clone)]
+    /// participacion de los votantes de una eleccion, los porcentajes van en puntos basicos (10000 es el 100%)
+    /// y son 0 cuando no hay votantes sobre los que calcularlos.
+    #[derive(scale::Decode, scale::Encode,Debug,Default,Clone,PartialEq)]
     #[cfg_attr(
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
     )]
     pub struct Participacion{
-        cantidad_votos_emitidos:i16,
-        porcentaje_de_votacion:i16,
+        postulados:u32,//se postularon como votantes en la eleccion, esten pendientes, aprobados o rechazados.
+        aprobados:u32,
+        votaron:u32,
+        abstenciones:u32,//aprobados que no votaron.
+        sobre_aprobados:u32,
+        sobre_postulados:u32,
     }
 
     impl Participacion{
-        /// crea una instancia departicipacion
-        pub fn new() -> Self {
+        /// crea la participacion a partir de las cantidades y calcula las abstenciones y los porcentajes
+        pub fn new(postulados:u32, aprobados:u32, votaron:u32) -> Self {
             Participacion{
-                cantidad_votos_emitidos:0,
-                porcentaje_de_votacion:0,
+                postulados,
+                aprobados,
+                votaron,
+                abstenciones:aprobados.saturating_sub(votaron),
+                sobre_aprobados:porcentaje(votaron as i64, aprobados as i64),
+                sobre_postulados:porcentaje(votaron as i64, postulados as i64),
             }
         }
-        /// settea la cantidad de votos 
-        pub fn agregar_cantidad_votos_emitidos(&mut self, cantidad:i16) {
-            self.cantidad_votos_emitidos = cantidad;
+        pub fn get_postulados(&self) -> u32 {
+            self.postulados
+        }
+        pub fn get_aprobados(&self) -> u32 {
+            self.aprobados
+        }
+        pub fn get_votaron(&self) -> u32 {
+            self.votaron
+        }
+        pub fn get_abstenciones(&self) -> u32 {
+            self.abstenciones
         }
-        ///setea el pocentaje de votacion 
-        pub fn agregar_porcentaje_de_votacion(&mut self, porcentaje:i16) {
-            self.porcentaje_de_votacion = porcentaje;
+        /// votaron sobre aprobados, en puntos basicos
+        pub fn get_sobre_aprobados(&self) -> u32 {
+            self.sobre_aprobados
+        }
+        /// votaron sobre postulados, en puntos basicos
+        pub fn get_sobre_postulados(&self) -> u32 {
+            self.sobre_postulados
         }
     }
 
@@ -42,6 +72,7 @@ mod reporte {
     pub struct Votantes {
         registrados:Vec<sistema_de_votacion::sistema_de_votacion::Votante>,
         aprobados:Vec<sistema_de_votacion::sistema_de_votacion::Votante>,
+        rechazados:Vec<sistema_de_votacion::sistema_de_votacion::Rechazo>,
     }
 
     impl Votantes{
@@ -50,6 +81,7 @@ mod reporte {
             Votantes {
                 registrados: Vec::new(),
                 aprobados: Vec::new(),
+                rechazados: Vec::new(),
             }
         }
         ///setea los votantes registrados
@@ -60,101 +92,837 @@ mod reporte {
         pub fn agregar_aprobados(&mut self, aprobados: Vec<sistema_de_votacion::sistema_de_votacion::Votante>) {
             self.aprobados = aprobados;
         }
+        ///setea los votantes rechazados, con el motivo y el estado de su apelacion
+        pub fn agregar_rechazados(&mut self, rechazados: Vec<sistema_de_votacion::sistema_de_votacion::Rechazo>) {
+            self.rechazados = rechazados;
+        }
     }
     
+    #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ResultadoDeLista {
+        nombre:String,
+        cantidad_votos:i16,
+        candidatos:Vec<sistema_de_votacion::sistema_de_votacion::Candidato>,//en el orden de la lista, el primero es el lider.
+    }
+
+    impl ResultadoDeLista{
+        /// instancia del resultado de una lista
+        pub fn new(nombre:String, cantidad_votos:i16, candidatos:Vec<sistema_de_votacion::sistema_de_votacion::Candidato>) -> Self {
+            ResultadoDeLista { nombre, cantidad_votos, candidatos }
+        }
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ResultadoDeCargo {
+        cargo:String,
+        candidatos:Vec<sistema_de_votacion::sistema_de_votacion::Candidato>,//del mas votado al menos votado.
+    }
+
+    impl ResultadoDeCargo{
+        /// instancia del resultado de un cargo
+        pub fn new(cargo:String, candidatos:Vec<sistema_de_votacion::sistema_de_votacion::Candidato>) -> Self {
+            ResultadoDeCargo { cargo, candidatos }
+        }
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ResultadoDeReferendum {
+        pregunta:String,
+        opciones:Vec<sistema_de_votacion::sistema_de_votacion::Opcion>,
+        regla_de_mayoria:sistema_de_votacion::sistema_de_votacion::ReglaDeMayoria,
+        opcion_ganadora:Option<i16>,//id de la opcion que cumple la regla de mayoria, None si ninguna la cumple.
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ReporteDeEleccion {
+        eleccion:sistema_de_votacion::sistema_de_votacion::Eleccion,
+        estado_de_certificacion:sistema_de_votacion::sistema_de_votacion::EstadoDeCertificacion,
+        firmantes:Vec<AccountId>,//auditores que certificaron el resultado final.
+        disputas:Vec<sistema_de_votacion::sistema_de_votacion::Disputa>,
+    }
+
+    impl ReporteDeEleccion {
+        pub fn get_eleccion(&self) -> sistema_de_votacion::sistema_de_votacion::Eleccion {
+            self.eleccion.clone()
+        }
+        pub fn get_estado_de_certificacion(&self) -> sistema_de_votacion::sistema_de_votacion::EstadoDeCertificacion {
+            self.estado_de_certificacion
+        }
+        pub fn get_firmantes(&self) -> Vec<AccountId> {
+            self.firmantes.clone()
+        }
+        pub fn get_disputas(&self) -> Vec<sistema_de_votacion::sistema_de_votacion::Disputa> {
+            self.disputas.clone()
+        }
+    }
+
+    /// candidato dentro del resultado, los empatados comparten el puesto y el siguiente lo saltea (1, 1, 3).
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PuestoDeCandidato {
+        puesto:u32,
+        candidato:sistema_de_votacion::sistema_de_votacion::Candidato,
+        porcentaje:u32,//sobre los votos emitidos, en puntos basicos.
+    }
+
+    impl PuestoDeCandidato {
+        pub fn get_puesto(&self) -> u32 {
+            self.puesto
+        }
+        pub fn get_candidato(&self) -> sistema_de_votacion::sistema_de_votacion::Candidato {
+            self.candidato.clone()
+        }
+        pub fn get_porcentaje(&self) -> u32 {
+            self.porcentaje
+        }
+    }
+
+    /// resultado de un cargo, los porcentajes van en puntos basicos sobre los votos emitidos, que incluyen los votos en blanco.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Resultado {
+        cargo:String,
+        candidatos:Vec<PuestoDeCandidato>,//del mas votado al menos votado, a igual cantidad de votos por numero de boleta.
+        votos_emitidos:u32,
+        votos_en_blanco:u32,
+        porcentaje_en_blanco:u32,
+        margen:u32,//entre el primero y el segundo, 0 si empataron.
+        empate:bool,//hay mas de un candidato en el primer puesto.
+    }
+
+    impl Resultado {
+        /// ordena los candidatos y calcula los puestos, los porcentajes y el margen
+        pub fn new(cargo:String, mut candidatos:Vec<sistema_de_votacion::sistema_de_votacion::Candidato>, votos_emitidos:u32, votos_en_blanco:u32) -> Self {
+            candidatos.sort_by_key(|candi| (Reverse(candi.get_cantidad_votos()), candi.get_numero_de_boleta()));
+            let votos:Vec<i64> = candidatos.iter().map(|candi| candi.get_cantidad_votos() as i64).collect();
+            let mut puestos:Vec<PuestoDeCandidato> = Vec::new();
+            for (i, candidato) in candidatos.into_iter().enumerate(){
+                let puesto = match puestos.last(){
+                    Some(anterior) if anterior.candidato.get_cantidad_votos() == candidato.get_cantidad_votos() => anterior.puesto,
+                    _ => (i as u32).saturating_add(1),
+                };
+                puestos.push(PuestoDeCandidato{
+                    puesto,
+                    porcentaje:porcentaje(candidato.get_cantidad_votos() as i64, votos_emitidos as i64),
+                    candidato,
+                });
+            }
+            Resultado{
+                cargo,
+                empate:puestos.get(1).is_some_and(|segundo| segundo.puesto == 1),
+                candidatos:puestos,
+                votos_emitidos,
+                votos_en_blanco,
+                porcentaje_en_blanco:porcentaje(votos_en_blanco as i64, votos_emitidos as i64),
+                margen:margen_de_victoria(&votos, votos_emitidos as i64),
+            }
+        }
+        pub fn get_cargo(&self) -> String {
+            self.cargo.clone()
+        }
+        pub fn get_candidatos(&self) -> Vec<PuestoDeCandidato> {
+            self.candidatos.clone()
+        }
+        /// los candidatos del primer puesto, mas de uno si hubo empate
+        pub fn get_ganadores(&self) -> Vec<PuestoDeCandidato> {
+            self.candidatos.iter().filter(|candi| candi.puesto == 1).cloned().collect()
+        }
+        pub fn get_votos_emitidos(&self) -> u32 {
+            self.votos_emitidos
+        }
+        pub fn get_votos_en_blanco(&self) -> u32 {
+            self.votos_en_blanco
+        }
+        pub fn get_porcentaje_en_blanco(&self) -> u32 {
+            self.porcentaje_en_blanco
+        }
+        pub fn get_margen(&self) -> u32 {
+            self.margen
+        }
+        pub fn get_empate(&self) -> bool {
+            self.empate
+        }
+    }
+
+    /// votos de un intervalo de la linea de tiempo, con lo acumulado hasta su fin.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct IntervaloDeVotos {
+        desde:u64,//timestamp en milisegundos del inicio del intervalo.
+        votos:u32,
+        acumulados:u32,
+        participacion_acumulada:u32,//acumulados sobre los votantes aprobados, en puntos basicos.
+    }
+
+    impl IntervaloDeVotos {
+        pub fn get_desde(&self) -> u64 {
+            self.desde
+        }
+        pub fn get_votos(&self) -> u32 {
+            self.votos
+        }
+        pub fn get_acumulados(&self) -> u32 {
+            self.acumulados
+        }
+        pub fn get_participacion_acumulada(&self) -> u32 {
+            self.participacion_acumulada
+        }
+    }
+
+    /// como fue llegando la participacion a lo largo de la eleccion, solo aparecen los intervalos con votos.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct LineaDeTiempo {
+        duracion_del_intervalo:u64,//milisegundos.
+        intervalos:Vec<IntervaloDeVotos>,
+    }
+
+    impl LineaDeTiempo {
+        /// acumula los votos de cada intervalo sobre la cantidad de votantes aprobados
+        pub fn new(duracion_del_intervalo:u64, votos_por_intervalo:Vec<(u64, u32)>, aprobados:u32) -> Self {
+            let mut acumulados:u32 = 0;
+            let intervalos = votos_por_intervalo.into_iter()
+                .map(|(desde, votos)| {
+                    acumulados = acumulados.saturating_add(votos);
+                    IntervaloDeVotos{
+                        desde,
+                        votos,
+                        acumulados,
+                        participacion_acumulada:porcentaje(acumulados as i64, aprobados as i64),
+                    }
+                })
+                .collect();
+            LineaDeTiempo{ duracion_del_intervalo, intervalos }
+        }
+        pub fn get_duracion_del_intervalo(&self) -> u64 {
+            self.duracion_del_intervalo
+        }
+        pub fn get_intervalos(&self) -> Vec<IntervaloDeVotos> {
+            self.intervalos.clone()
+        }
+    }
+
+    /// resumen de una eleccion cerrada dentro del reporte historico, los porcentajes van en puntos basicos.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct HistorialDeEleccion {
+        id_eleccion:i16,
+        cargo:String,
+        fin:i64,
+        participacion:Participacion,
+        cantidad_de_candidatos:u32,
+        margen_de_victoria:u32,//diferencia entre el primero y el segundo del primer cargo, u opciones si es un referendum, sobre los votos emitidos.
+    }
+
+    impl HistorialDeEleccion {
+        pub fn get_id_eleccion(&self) -> i16 {
+            self.id_eleccion
+        }
+        pub fn get_cargo(&self) -> String {
+            self.cargo.clone()
+        }
+        pub fn get_fin(&self) -> i64 {
+            self.fin
+        }
+        pub fn get_participacion(&self) -> Participacion {
+            self.participacion.clone()
+        }
+        pub fn get_cantidad_de_candidatos(&self) -> u32 {
+            self.cantidad_de_candidatos
+        }
+        pub fn get_margen_de_victoria(&self) -> u32 {
+            self.margen_de_victoria
+        }
+    }
+
+    /// como le fue a un candidato en una de las elecciones en las que se presento.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct DesempenioDeCandidato {
+        id_eleccion:i16,
+        cargo:String,
+        votos:u32,
+        porcentaje:u32,//sobre los votos emitidos, en puntos basicos.
+        gano:bool,//fue el mas votado de su cargo, con al menos un voto.
+    }
+
+    impl DesempenioDeCandidato {
+        pub fn get_id_eleccion(&self) -> i16 {
+            self.id_eleccion
+        }
+        pub fn get_cargo(&self) -> String {
+            self.cargo.clone()
+        }
+        pub fn get_votos(&self) -> u32 {
+            self.votos
+        }
+        pub fn get_porcentaje(&self) -> u32 {
+            self.porcentaje
+        }
+        pub fn get_gano(&self) -> bool {
+            self.gano
+        }
+    }
+
+    /// candidato que se presento en mas de una eleccion cerrada, se lo reconoce por su dni.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct CandidatoRepetido {
+        nombre:String,
+        apellido:String,
+        desempenios:Vec<DesempenioDeCandidato>,//en el orden en que se crearon las elecciones.
+    }
+
+    impl CandidatoRepetido {
+        pub fn get_nombre(&self) -> String {
+            self.nombre.clone()
+        }
+        pub fn get_apellido(&self) -> String {
+            self.apellido.clone()
+        }
+        pub fn get_desempenios(&self) -> Vec<DesempenioDeCandidato> {
+            self.desempenios.clone()
+        }
+    }
+
+    /// reporte de una pagina de elecciones cerradas, en el orden en que se crearon. el margen promedio y los
+    /// candidatos repetidos se calculan sobre las elecciones de la pagina.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Historial {
+        elecciones:Vec<HistorialDeEleccion>,
+        margen_promedio:u32,//promedio de los margenes de victoria de las elecciones con votos emitidos.
+        candidatos_repetidos:Vec<CandidatoRepetido>,
+        siguiente:Option<u32>,//valor de desde para pedir la proxima pagina, None si no hay mas elecciones.
+    }
+
+    impl Historial {
+        /// arma el historial a partir de los resumenes de las elecciones cerradas y su id
+        pub fn new(elecciones:Vec<(i16, sistema_de_votacion::sistema_de_votacion::ResumenDeEleccionCerrada)>, siguiente:Option<u32>) -> Self {
+            let mut resumenes = Vec::new();
+            let mut margenes:Vec<u32> = Vec::new();
+            let mut candidatos:Vec<(String, CandidatoRepetido)> = Vec::new();//dni y desempenios de cada candidato.
+            for (id_eleccion, elec) in elecciones{
+                let emitidos = elec.get_votos_emitidos() as i64;
+                let cargos = elec.get_cargos();
+                let votos_del_primer_cargo:Vec<i64> = if elec.get_tipo() == sistema_de_votacion::sistema_de_votacion::TipoDeEleccion::Referendum{
+                    elec.get_opciones().iter().map(|opcion| opcion.get_cantidad_votos() as i64).collect()
+                }else{
+                    elec.get_candidatos_de_cargo(1).iter().map(|candi| candi.get_cantidad_votos() as i64).collect()
+                };
+                let margen = margen_de_victoria(&votos_del_primer_cargo, emitidos);
+                if emitidos > 0{
+                    margenes.push(margen);
+                }
+                for candidato in elec.get_candidatos(){
+                    let votos = candidato.get_cantidad_votos() as i64;
+                    let mas_votado = elec.get_candidatos_de_cargo(candidato.get_id_cargo()).iter()
+                        .map(|candi| candi.get_cantidad_votos() as i64)
+                        .max()
+                        .unwrap_or(0);
+                    let desempenio = DesempenioDeCandidato{
+                        id_eleccion,
+                        cargo:cargos.get(candidato.get_id_cargo().saturating_sub(1) as usize).cloned().unwrap_or_default(),
+                        votos:votos.max(0) as u32,
+                        porcentaje:porcentaje(votos, emitidos),
+                        gano:votos > 0 && votos == mas_votado,
+                    };
+                    let dato = candidato.get_dato();
+                    match candidatos.iter_mut().find(|(dni, _)| *dni == dato.get_dni()){
+                        Some((_, repetido)) => repetido.desempenios.push(desempenio),
+                        None => candidatos.push((dato.get_dni(), CandidatoRepetido{
+                            nombre:dato.get_nombre(),
+                            apellido:dato.get_apellido(),
+                            desempenios:vec![desempenio],
+                        })),
+                    }
+                }
+                resumenes.push(HistorialDeEleccion{
+                    id_eleccion,
+                    cargo:elec.get_cargo(),
+                    fin:elec.get_fin(),
+                    participacion:Participacion::new(elec.get_postulados_a_votantes(), elec.get_votantes_aprobados(), elec.get_votos_emitidos()),
+                    cantidad_de_candidatos:elec.get_candidatos().len() as u32,
+                    margen_de_victoria:margen,
+                });
+            }
+            let margen_promedio = if margenes.is_empty(){
+                0
+            }else{
+                (margenes.iter().map(|margen| *margen as u64).sum::<u64>() / margenes.len() as u64) as u32
+            };
+            Historial{
+                elecciones:resumenes,
+                margen_promedio,
+                candidatos_repetidos:candidatos.into_iter()
+                    .map(|(_, repetido)| repetido)
+                    .filter(|repetido| repetido.desempenios.len() > 1)
+                    .collect(),
+                siguiente,
+            }
+        }
+        pub fn get_elecciones(&self) -> Vec<HistorialDeEleccion> {
+            self.elecciones.clone()
+        }
+        /// participacion sobre los aprobados de cada eleccion, para ver la tendencia
+        pub fn get_tendencia_de_participacion(&self) -> Vec<(i16, u32)> {
+            self.elecciones.iter().map(|elec| (elec.id_eleccion, elec.participacion.get_sobre_aprobados())).collect()
+        }
+        pub fn get_margen_promedio(&self) -> u32 {
+            self.margen_promedio
+        }
+        pub fn get_candidatos_repetidos(&self) -> Vec<CandidatoRepetido> {
+            self.candidatos_repetidos.clone()
+        }
+        pub fn get_siguiente(&self) -> Option<u32> {
+            self.siguiente
+        }
+    }
+
+    /// diferencia entre los dos mas votados sobre los emitidos, en puntos basicos. con un solo contendiente es todo su porcentaje.
+    fn margen_de_victoria(votos:&[i64], emitidos:i64) -> u32 {
+        let mut ordenados = votos.to_vec();
+        ordenados.sort_by_key(|votos| Reverse(*votos));
+        let primero = ordenados.first().copied().unwrap_or(0);
+        let segundo = ordenados.get(1).copied().unwrap_or(0);
+        porcentaje(primero.saturating_sub(segundo), emitidos)
+    }
+
+    /// resumen de una eleccion finalizada que el reporte guarda para no volver a pedir la eleccion entera al sistema.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ResumenDeEleccion {
+        hash_del_resultado:Hash,//hash del resultado final del que se armo, para compararlo con el del sistema.
+        resultado:Resultado,
+        participacion:Participacion,
+    }
+
+    impl ResumenDeEleccion {
+        pub fn get_hash_del_resultado(&self) -> Hash {
+            self.hash_del_resultado
+        }
+        pub fn get_resultado(&self) -> Resultado {
+            self.resultado.clone()
+        }
+        pub fn get_participacion(&self) -> Participacion {
+            self.participacion.clone()
+        }
+    }
+
+    /// el sistema de votacion autoriza a la cuenta de este contrato, y el reporte decide que usuarios pueden consultarlo:
+    /// su administrador, que es quien lo instancio, y los usuarios que este autorice.
     #[ink(storage)]
     pub struct Reporte {
         sistema_de_votacion:SistemaDeVotacionRef,
+        admin:AccountId,
+        usuarios_autorizados:Vec<AccountId>,
+        resumenes:Mapping<i16, ResumenDeEleccion>,//por id de eleccion, solo de elecciones finalizadas.
     }
 
     
     impl Reporte {
-        /// instancia de el reporte
+        /// instancia de el reporte y le pide acceso al sistema con la cuenta del contrato, el administrador lo tiene que aprobar.
+        /// si el pedido falla el reporte se crea igual y puede volver a pedirlo con solicitar_acceso.
         #[ink(constructor)]
         pub fn new(sistema_de_votacion:SistemaDeVotacionRef) -> Self {   
-            Self { sistema_de_votacion }
+            let mut reporte = Self { sistema_de_votacion, admin:Self::env().caller(), usuarios_autorizados:Vec::new(), resumenes:Mapping::default() };
+            let _ = reporte.sistema_de_votacion.solicitar_acceso();
+            reporte
+        }
+
+        /// le pide acceso al sistema con la cuenta de este contrato y devuelve en que estado quedo el pedido
+        #[ink(message)]
+        pub fn solicitar_acceso(&mut self) -> Result<EstadoDeReporte,String>{
+            self.sistema_de_votacion.solicitar_acceso()
+        }
+
+        /// devuelve si el pedido de acceso de este contrato al sistema esta pendiente, aprobado o rechazado
+        #[ink(message)]
+        pub fn estado_de_acceso(&self) -> EstadoDeReporte{
+            self.sistema_de_votacion.estado_de_reporte(Self::env().account_id())
+        }
+
+        /// permite a la cuenta consultar los reportes, solo lo puede hacer el administrador del reporte
+        #[ink(message)]
+        pub fn autorizar_usuario(&mut self, cuenta:AccountId) -> Result<(),String>{
+            if Self::env().caller() != self.admin{
+                return Err(String::from("No tiene permiso de administrador del reporte"));
+            }
+            if self.usuarios_autorizados.contains(&cuenta){
+                return Err(String::from("El usuario ya esta autorizado"));
+            }
+            self.usuarios_autorizados.push(cuenta);
+            Ok(())
+        }
+
+        /// quita el permiso de la cuenta para consultar los reportes, solo lo puede hacer el administrador del reporte
+        #[ink(message)]
+        pub fn revocar_usuario(&mut self, cuenta:AccountId) -> Result<(),String>{
+            if Self::env().caller() != self.admin{
+                return Err(String::from("No tiene permiso de administrador del reporte"));
+            }
+            match self.usuarios_autorizados.iter().position(|autorizado| *autorizado == cuenta){
+                Some(posicion) => {
+                    self.usuarios_autorizados.remove(posicion);
+                    Ok(())
+                }
+                None => Err(String::from("El usuario no esta autorizado")),
+            }
         }
 
+        /// devuelve el administrador del reporte y los usuarios que autorizo
         #[ink(message)]
-        pub fn get_reporte_de_eleccion(&self,id_eleccion:i16) -> Result<sistema_de_votacion::sistema_de_votacion::Eleccion, String>{
-            let eleccion = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion);
-            let eleccion = match eleccion{
-                Ok(dato) => dato,
-                Err(e) => return Err(e),
+        pub fn obtener_usuarios_autorizados(&self) -> (AccountId, Vec<AccountId>){
+            (self.admin, self.usuarios_autorizados.clone())
+        }
+
+        /// quien llama tiene que ser el administrador del reporte o un usuario autorizado. si el sistema no aprobo a este
+        /// contrato, la llamada al sistema devuelve el error con el estado del pedido de acceso.
+        fn verificar_acceso(&self) -> Result<(),String>{
+            let caller = Self::env().caller();
+            if caller != self.admin && !self.usuarios_autorizados.contains(&caller){
+                return Err(String::from("El usuario no esta autorizado a consultar el reporte"));
             };
-            Ok(eleccion)
+            Ok(())
+        }
+
+        /// devuelve la eleccion junto con el estado de la certificacion de su resultado y los auditores que lo firmaron
+        #[ink(message)]
+        pub fn get_reporte_de_eleccion(&self,id_eleccion:i16) -> Result<ReporteDeEleccion, String>{
+            self.verificar_acceso()?;
+            let eleccion = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            Ok(ReporteDeEleccion {
+                estado_de_certificacion: eleccion.get_estado_de_certificacion(),
+                firmantes: eleccion.get_firmantes(),
+                disputas: eleccion.get_disputas(),
+                eleccion,
+            })
         }
 
         
         #[ink(message)]
         pub fn reporte_de_eleccion(&self,id_eleccion:i16) ->Result<Votantes,String>{
-            let reporte=self.sistema_de_votacion.obtener_reportes_aprobados();
-            let reporte = match reporte{
-                Ok(dato) => dato,
-                Err(e) => return Err(e),
-            };
-            if !reporte.contains(&Self::env().caller()){
-                return Err(String::from("El contract no tiene permiso para obtener el reporte"));
-            };
+            self.verificar_acceso()?;
 
             let mut votantes=Votantes::new();
-            let eleccion = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion);
-            match eleccion {
-                Ok(elec) => {
-                    votantes.agregar_registrados(elec.get_postulados_a_votantes());
-                    votantes.agregar_aprobados(elec.get_votantes());
-                    return Ok(votantes)
-                }
-                Err(e)=> return Err(e),
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            votantes.agregar_registrados(elec.get_postulados_a_votantes());
+            votantes.agregar_aprobados(elec.get_votantes());
+            votantes.agregar_rechazados(elec.get_rechazados().into_iter()
+                .filter(|rechazo| rechazo.get_rol() == sistema_de_votacion::sistema_de_votacion::Rol::Votante)
+                .collect());
+            Ok(votantes)
+        }
+
+
+        /// devuelve cuantos votantes se postularon a la eleccion, cuantos fueron aprobados, cuantos votaron y cuantos se abstuvieron,
+        /// con la participacion sobre los aprobados y sobre los postulados. si la eleccion esta finalizada guarda su resumen
+        /// la primera vez y despues lo sirve desde el reporte, sin llamar al sistema.
+        #[ink(message)]
+        pub fn reporte_de_participacion(&mut self,id_eleccion:i16) -> Result<Participacion,String>{
+            self.verificar_acceso()?;
+            if let Some(resumen) = self.resumenes.get(id_eleccion){
+                return Ok(resumen.participacion);
             }
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            self.guardar_resumen(id_eleccion, &elec);
+            Ok(Self::participacion_de(&elec))
         }
 
+        /// devuelve el resumen con el resultado y la participacion de una eleccion finalizada. la primera consulta de
+        /// cualquiera de los reportes lo guarda y las siguientes lo sirven desde el reporte, sin llamar al sistema.
+        #[ink(message)]
+        pub fn resumen_de_eleccion(&mut self,id_eleccion:i16) -> Result<ResumenDeEleccion,String>{
+            self.verificar_acceso()?;
+            if let Some(resumen) = self.resumenes.get(id_eleccion){
+                return Ok(resumen);
+            }
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            self.guardar_resumen(id_eleccion, &elec).ok_or(String::from("La eleccion no esta finalizada"))
+        }
 
+        /// devuelve si el reporte ya guardo el resumen de la eleccion, o sea si la sirve sin llamar al sistema.
         #[ink(message)]
-        pub fn reporte_de_participacion(&self,id_eleccion:i16) -> Result<Participacion,String>{
-            let reporte=self.sistema_de_votacion.obtener_reportes_aprobados();
-            let reporte = match reporte{
-                Ok(dato) => dato,
-                Err(e) => return Err(e),
-            };
-            if !reporte.contains(&Self::env().caller()){
-                return Err(String::from("El contract no tiene permiso para obtener el reporte"));
+        pub fn tiene_resumen_guardado(&self,id_eleccion:i16) -> Result<bool,String>{
+            self.verificar_acceso()?;
+            Ok(self.resumenes.contains(id_eleccion))
+        }
+
+        /// guarda el resumen si la eleccion esta finalizada. su resultado ya no cambia y el sistema no deja eliminar una
+        /// eleccion anterior a una finalizada, asi que el id sigue siendo de la misma eleccion y el resumen no vence.
+        fn guardar_resumen(&mut self,id_eleccion:i16,elec:&sistema_de_votacion::sistema_de_votacion::Eleccion) -> Option<ResumenDeEleccion>{
+            let resumen = ResumenDeEleccion{
+                hash_del_resultado:elec.get_hash_del_resultado()?,
+                resultado:Self::resultado_de(elec),
+                participacion:Self::participacion_de(elec),
             };
-            let mut participacion=Participacion::new();
-            let eleccion = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion);
-            match eleccion {
-                Ok(elec) => {
-                    let cantidad=elec.get_cantidad_de_votos_emitidos();
-                    participacion.agregar_cantidad_votos_emitidos(cantidad);
-                    let porcentaje:i16=elec.get_cantidad_de_votantes().checked_div(cantidad).unwrap();
-                    participacion.agregar_porcentaje_de_votacion(porcentaje);
-                    return Ok(participacion)
+            self.resumenes.insert(id_eleccion, &resumen);
+            Some(resumen)
+        }
+
+        fn participacion_de(elec:&sistema_de_votacion::sistema_de_votacion::Eleccion) -> Participacion{
+            let aprobados = elec.get_cantidad_de_votantes().max(0) as u32;
+            Participacion::new(elec.get_cantidad_de_postulados_a_votantes(), aprobados, elec.get_cantidad_de_votos_emitidos().max(0) as u32)
+        }
+        
+        /// devuelve el resultado del cargo de la eleccion: los candidatos del mas votado al menos votado con su porcentaje,
+        /// el margen entre los dos primeros, si hubo empate y los votos en blanco. los demas cargos estan en reporte_de_resultado_por_cargo.
+        /// si la eleccion esta finalizada guarda su resumen la primera vez y despues lo sirve desde el reporte, sin llamar al sistema.
+        #[ink(message)]
+        pub fn reporte_de_resultado(&mut self,id_eleccion:i16) -> Result<Resultado,String>{
+            self.verificar_acceso()?;
+
+            if let Some(resumen) = self.resumenes.get(id_eleccion){
+                return Ok(resumen.resultado);
+            }
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            self.guardar_resumen(id_eleccion, &elec);
+            Ok(Self::resultado_de(&elec))
+        }
+
+        fn resultado_de(elec:&sistema_de_votacion::sistema_de_votacion::Eleccion) -> Resultado{
+            Resultado::new(
+                elec.get_cargo(),
+                elec.get_candidatos_de_cargo(1),
+                elec.get_cantidad_de_votos_emitidos().max(0) as u32,
+                elec.get_votos_en_blanco().max(0) as u32,
+            )
+        }
+
+        /// devuelve los candidatos de cada cargo de la eleccion, del mas votado al menos votado
+        #[ink(message)]
+        pub fn reporte_de_resultado_por_cargo(&self,id_eleccion:i16) -> Result<Vec<ResultadoDeCargo>,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            let resultado = elec.get_cargos().into_iter().enumerate()
+                .map(|(i, cargo)| {
+                    let mut candidatos = elec.get_candidatos_de_cargo((i as i16).saturating_add(1));
+                    candidatos.sort_by_key(|candi| Reverse(candi.get_cantidad_votos()));
+                    ResultadoDeCargo::new(cargo, candidatos)
+                })
+                .collect();
+            Ok(resultado)
+        }
+
+        /// devuelve los votos de cada opcion del referendum y la opcion ganadora segun su regla de mayoria
+        #[ink(message)]
+        pub fn reporte_de_referendum(&self,id_eleccion:i16) -> Result<ResultadoDeReferendum,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            if elec.get_tipo() != sistema_de_votacion::sistema_de_votacion::TipoDeEleccion::Referendum{
+                return Err(String::from("La eleccion no es un referendum"));
+            }
+            Ok(ResultadoDeReferendum{
+                pregunta:elec.get_cargo(),
+                opciones:elec.get_opciones(),
+                regla_de_mayoria:elec.get_regla_de_mayoria(),
+                opcion_ganadora:elec.get_opcion_ganadora(),
+            })
+        }
+
+        /// devuelve todas las postulaciones rechazadas de la eleccion, de votantes y de candidatos, con su motivo y apelacion
+        #[ink(message)]
+        pub fn reporte_de_rechazos(&self,id_eleccion:i16) -> Result<Vec<sistema_de_votacion::sistema_de_votacion::Rechazo>,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            Ok(elec.get_rechazados())
+        }
+
+        /// devuelve los votos de cada lista con sus candidatos en orden, de la mas votada a la menos votada
+        #[ink(message)]
+        pub fn reporte_de_listas(&self,id_eleccion:i16) -> Result<Vec<ResultadoDeLista>,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            let mut resultado: Vec<ResultadoDeLista> = elec.get_listas().iter()
+                .map(|lista| ResultadoDeLista::new(lista.get_nombre(), lista.get_cantidad_votos(), elec.get_candidatos_de_lista(lista)))
+                .collect();
+            resultado.sort_by_key(|lista| Reverse(lista.cantidad_votos));
+            Ok(resultado)
+        }
+
+        /// devuelve el deposito de cada candidato con su monto y si fue reintegrado, perdido o sigue retenido
+        #[ink(message)]
+        pub fn reporte_de_depositos(&self,id_eleccion:i16) -> Result<Vec<sistema_de_votacion::sistema_de_votacion::Deposito>,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            Ok(elec.get_depositos())
+        }
+
+        /// devuelve el resultado como texto CSV o JSON, una fila por candidato de cada cargo del mas votado al menos votado,
+        /// o una fila por opcion si es un referendum. el porcentaje es sobre los votos emitidos.
+        #[ink(message)]
+        pub fn exportar_resultado(&self,id_eleccion:i16,formato:Formato) -> Result<String,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            let emitidos = elec.get_cantidad_de_votos_emitidos() as i64;
+            let mut tabla = Tabla::new(COLUMNAS_DE_RESULTADO);
+            if elec.get_tipo() == sistema_de_votacion::sistema_de_votacion::TipoDeEleccion::Referendum{
+                for (i, opcion) in elec.get_opciones().into_iter().enumerate(){
+                    let votos = opcion.get_cantidad_votos() as i64;
+                    tabla.agregar_fila(vec![
+                        Valor::Texto(elec.get_cargo()),
+                        Valor::Entero((i as i64).saturating_add(1)),
+                        Valor::Texto(opcion.get_texto()),
+                        Valor::Texto(String::new()),
+                        Valor::Texto(String::new()),
+                        Valor::Entero(votos),
+                        Valor::Porcentaje(porcentaje(votos, emitidos)),
+                    ]);
+                }
+                return Ok(tabla.exportar(formato));
+            }
+            for (i, cargo) in elec.get_cargos().into_iter().enumerate(){
+                let mut candidatos = elec.get_candidatos_de_cargo((i as i16).saturating_add(1));
+                candidatos.sort_by_key(|candi| Reverse(candi.get_cantidad_votos()));
+                for candidato in candidatos{
+                    let ficha = candidato.get_ficha();
+                    let votos = candidato.get_cantidad_votos() as i64;
+                    tabla.agregar_fila(vec![
+                        Valor::Texto(cargo.clone()),
+                        Valor::Entero(ficha.get_numero_de_boleta() as i64),
+                        Valor::Texto(ficha.get_nombre()),
+                        Valor::Texto(ficha.get_apellido()),
+                        Valor::Texto(ficha.get_perfil().get_partido()),
+                        Valor::Entero(votos),
+                        Valor::Porcentaje(porcentaje(votos, emitidos)),
+                    ]);
                 }
-                Err(e)=>return Err(e),
             }
+            Ok(tabla.exportar(formato))
         }
-        
+
+        /// devuelve cuantos votos hubo en cada intervalo de la eleccion y la participacion acumulada, sin decir quien voto en cada uno
         #[ink(message)]
-        pub fn reporte_de_resultado(&self,id_eleccion:i16) -> Result<Vec<sistema_de_votacion::sistema_de_votacion::Candidato>,String>{
-            let reporte=self.sistema_de_votacion.obtener_reportes_aprobados();
-            let reporte = match reporte{
-                Ok(dato) => dato,
-                Err(e) => return Err(e),
-            };
-            if !reporte.contains(&Self::env().caller()){
-                return Err(String::from("El contract no tiene permiso para obtener el reporte"));
-            };
+        pub fn reporte_de_linea_de_tiempo(&self,id_eleccion:i16) -> Result<LineaDeTiempo,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            Ok(LineaDeTiempo::new(
+                elec.get_intervalo_de_votos(),
+                elec.get_votos_por_intervalo(),
+                elec.get_cantidad_de_votantes().max(0) as u32,
+            ))
+        }
+
+        /// devuelve un resumen de una pagina de elecciones cerradas: la participacion de cada una, cuantos candidatos tuvo,
+        /// el margen de victoria y su promedio, y como le fue a los candidatos que se presentaron mas de una vez.
+        /// desde y cantidad se pasan al sistema, que admite hasta 50 elecciones por pagina.
+        #[ink(message)]
+        pub fn reporte_historico(&self,desde:u32,cantidad:u32) -> Result<Historial,String>{
+            self.verificar_acceso()?;
+
+            let pagina = self.sistema_de_votacion.obtener_resumenes_de_elecciones_cerradas(desde, cantidad)?;
+            Ok(Historial::new(pagina.get_elementos(), pagina.get_siguiente()))
+        }
 
-            let mut resultado: Vec<sistema_de_votacion::sistema_de_votacion::Candidato>=Vec::new();
-            let eleccion = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion);
-            match eleccion {
-                Ok(elec) => {
-                    resultado=elec.get_candidatos();
-                    resultado.sort_unstable_by_key(|candi| candi.get_cantidad_votos());
-                    return Ok(resultado)
+        /// devuelve la participacion como texto CSV o JSON, con las mismas cantidades y porcentajes que reporte_de_participacion.
+        #[ink(message)]
+        pub fn exportar_participacion(&self,id_eleccion:i16,formato:Formato) -> Result<String,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            let participacion = Self::participacion_de(&elec);
+            let mut tabla = Tabla::new(COLUMNAS_DE_PARTICIPACION);
+            tabla.agregar_fila(vec![
+                Valor::Entero(participacion.get_aprobados() as i64),
+                Valor::Entero(participacion.get_votaron() as i64),
+                Valor::Porcentaje(participacion.get_sobre_aprobados()),
+                Valor::Entero(participacion.get_postulados() as i64),
+                Valor::Entero(participacion.get_abstenciones() as i64),
+                Valor::Porcentaje(participacion.get_sobre_postulados()),
+            ]);
+            Ok(tabla.exportar(formato))
+        }
+
+        /// devuelve el padron de votantes como texto CSV o JSON, con los postulados, los aprobados y los rechazados con su motivo.
+        /// un rechazado con la apelacion aceptada aparece una sola vez, como aprobado.
+        #[ink(message)]
+        pub fn exportar_eleccion(&self,id_eleccion:i16,formato:Formato) -> Result<String,String>{
+            self.verificar_acceso()?;
+
+            let elec = self.sistema_de_votacion.obtener_reporte_de_eleccion(id_eleccion)?;
+            Ok(Self::padron_de(&elec).exportar(formato))
+        }
+
+        fn padron_de(elec:&sistema_de_votacion::sistema_de_votacion::Eleccion) -> Tabla{
+            let mut tabla = Tabla::new(COLUMNAS_DE_ELECCION);
+            let fila = |estado:&str, dato:&sistema_de_votacion::sistema_de_votacion::Persona, motivo:&str| vec![
+                Valor::Texto(String::from(estado)),
+                Valor::Texto(dato.get_nombre()),
+                Valor::Texto(dato.get_apellido()),
+                Valor::Texto(dato.get_dni()),
+                Valor::Texto(String::from(motivo)),
+            ];
+            for votante in elec.get_postulados_a_votantes(){
+                tabla.agregar_fila(fila("postulado", &votante.get_dato(), ""));
+            }
+            for votante in elec.get_votantes(){
+                tabla.agregar_fila(fila("aprobado", &votante.get_dato(), ""));
+            }
+            for rechazo in elec.get_rechazados(){
+                if rechazo.get_rol() != sistema_de_votacion::sistema_de_votacion::Rol::Votante
+                    || rechazo.get_apelacion() == sistema_de_votacion::sistema_de_votacion::EstadoDeApelacion::Aceptada{
+                    continue;
                 }
-                Err(e)=> return Err(e),
+                let motivo = match rechazo.get_motivo(){
+                    sistema_de_votacion::sistema_de_votacion::MotivoDeRechazo::SinEspecificar => "sin_especificar",
+                    sistema_de_votacion::sistema_de_votacion::MotivoDeRechazo::DatosIncompletos => "datos_incompletos",
+                    sistema_de_votacion::sistema_de_votacion::MotivoDeRechazo::NoHabilitado => "no_habilitado",
+                    sistema_de_votacion::sistema_de_votacion::MotivoDeRechazo::Duplicado => "duplicado",
+                    sistema_de_votacion::sistema_de_votacion::MotivoDeRechazo::Otro => "otro",
+                };
+                tabla.agregar_fila(fila("rechazado", rechazo.get_postulante().get_dato(), motivo));
             }
+            tabla
         }
         
     }
@@ -165,4 +933,324 @@ mod reporte {
       sistemaDeVotacionRef y con eso instanciar el Reporte. Intentamos las soluciones que nos sugeriste, así como algunas que encontramos 
       por nuestra cuenta y que nos recomendó ChatGPT, pero ninguna dio resultado. */
 
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn participacion_sin_votos_no_divide_por_cero(){
+            let participacion = Participacion::new(0, 0, 0);
+            assert_eq!(participacion.get_sobre_aprobados(), 0);
+            assert_eq!(participacion.get_sobre_postulados(), 0);
+            let participacion = Participacion::new(5, 4, 0);
+            assert_eq!(participacion.get_abstenciones(), 4);
+            assert_eq!(participacion.get_sobre_aprobados(), 0);
+        }
+
+        #[ink::test]
+        fn participacion_no_cuenta_dos_veces_una_apelacion_aceptada(){
+            use sistema_de_votacion::sistema_de_votacion::{MotivoDeRechazo, SistemaDeVotacion};
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"), String::from("1245623"));
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.rechazar_usuario(1, 1, MotivoDeRechazo::DatosIncompletos), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(sistema.apelar_rechazo(1, 1, String::from("Mi dni esta al dia")), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.resolver_apelacion(1, 1, true), Ok(()));
+
+            let eleccion = sistema.obtener_todas_las_elecciones().unwrap().remove(0);
+            let participacion = Reporte::participacion_de(&eleccion);
+            assert_eq!(participacion.get_postulados(), 1);
+            assert_eq!(participacion.get_aprobados(), 1);
+            assert_eq!(Reporte::padron_de(&eleccion).exportar(Formato::Csv), "estado,nombre,apellido,dni,motivo_de_rechazo\r\naprobado,Ana,Erazo,1245623,\r\n");
+        }
+
+        #[ink::test]
+        fn resumen_guardado_se_sirve_sin_llamar_al_sistema(){
+            use ink::env::call::FromAccountId;
+            use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            //el entorno de pruebas no puede llamar a otro contrato, si el reporte llamara al sistema la prueba fallaria
+            let mut reporte = Reporte{
+                sistema_de_votacion:SistemaDeVotacionRef::from_account_id(accounts.django),
+                admin:accounts.alice,
+                usuarios_autorizados:Vec::new(),
+                resumenes:Mapping::default(),
+            };
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);
+            let abierta = sistema.obtener_todas_las_elecciones().unwrap().remove(0);
+            assert_eq!(reporte.guardar_resumen(1, &abierta), None);
+            assert_eq!(reporte.tiene_resumen_guardado(1), Ok(false));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(abierta.get_fin() as u64 + 1);
+            let hash = sistema.finalizar_eleccion(1).unwrap();
+            let finalizada = sistema.obtener_todas_las_elecciones().unwrap().remove(0);
+            let resumen = reporte.guardar_resumen(1, &finalizada).unwrap();
+            assert_eq!(resumen.get_hash_del_resultado(), hash);
+            assert_eq!(reporte.tiene_resumen_guardado(1), Ok(true));
+            assert_eq!(reporte.reporte_de_resultado(1), Ok(resumen.get_resultado()));
+            assert_eq!(reporte.reporte_de_participacion(1), Ok(resumen.get_participacion()));
+            assert_eq!(reporte.resumen_de_eleccion(1), Ok(resumen));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(reporte.reporte_de_resultado(1).unwrap_err(), String::from("El usuario no esta autorizado a consultar el reporte"));
+        }
+
+        #[test]
+        fn participacion_en_puntos_basicos(){
+            let participacion = Participacion::new(8, 6, 3);
+            assert_eq!(participacion.get_abstenciones(), 3);
+            assert_eq!(participacion.get_sobre_aprobados(), 5000);
+            assert_eq!(participacion.get_sobre_postulados(), 3750);
+        }
+
+        #[test]
+        fn margen_entre_los_dos_mas_votados(){
+            assert_eq!(margen_de_victoria(&[2, 7, 4], 16), 1875);
+            assert_eq!(margen_de_victoria(&[3], 4), 7500);
+            assert_eq!(margen_de_victoria(&[], 0), 0);
+        }
+
+        fn candidato(nombre:&str, votos:i16, numero_de_boleta:i16) -> sistema_de_votacion::sistema_de_votacion::Candidato {
+            let dato = sistema_de_votacion::sistema_de_votacion::Persona::new(String::from(nombre), String::from("Perez"), String::from("123"), AccountId::from([0x01; 32]));
+            sistema_de_votacion::sistema_de_votacion::Candidato::con_votos(dato, numero_de_boleta, votos)
+        }
+
+        #[test]
+        fn resultado_ordenado_con_puestos_y_margen(){
+            let candidatos = vec![candidato("Ana", 2, 1), candidato("Luis", 5, 2), candidato("Eva", 2, 3)];
+            let resultado = Resultado::new(String::from("Presidente"), candidatos, 10, 1);
+            let puestos:Vec<(u32, i16, u32)> = resultado.get_candidatos().iter()
+                .map(|puesto| (puesto.get_puesto(), puesto.get_candidato().get_numero_de_boleta(), puesto.get_porcentaje()))
+                .collect();
+            assert_eq!(puestos, vec![(1, 2, 5000), (2, 1, 2000), (2, 3, 2000)]);
+            assert_eq!(resultado.get_cargo(), String::from("Presidente"));
+            assert_eq!(resultado.get_margen(), 3000);
+            assert_eq!(resultado.get_porcentaje_en_blanco(), 1000);
+            assert!(!resultado.get_empate());
+        }
+
+        #[test]
+        fn resultado_con_empate_y_sin_votos(){
+            let resultado = Resultado::new(String::from("Presidente"), vec![candidato("Ana", 4, 1), candidato("Luis", 4, 2)], 8, 0);
+            assert!(resultado.get_empate());
+            assert_eq!(resultado.get_ganadores().len(), 2);
+            assert_eq!(resultado.get_margen(), 0);
+            let resultado = Resultado::new(String::from("Presidente"), Vec::new(), 0, 0);
+            assert!(!resultado.get_empate());
+            assert_eq!(resultado.get_porcentaje_en_blanco(), 0);
+        }
+
+        #[test]
+        fn linea_de_tiempo_acumulada(){
+            let linea = LineaDeTiempo::new(3_600_000, vec![(0, 2), (7_200_000, 1)], 4);
+            let acumulados:Vec<(u64, u32, u32)> = linea.get_intervalos().iter()
+                .map(|intervalo| (intervalo.get_desde(), intervalo.get_acumulados(), intervalo.get_participacion_acumulada()))
+                .collect();
+            assert_eq!(acumulados, vec![(0, 2, 5000), (7_200_000, 3, 7500)]);
+            assert!(LineaDeTiempo::new(3_600_000, Vec::new(), 0).get_intervalos().is_empty());
+        }
+
+        fn persona(nombre:&str, dni:&str) -> sistema_de_votacion::sistema_de_votacion::Persona {
+            sistema_de_votacion::sistema_de_votacion::Persona::new(String::from(nombre), String::from("Perez"), String::from(dni), AccountId::from([0x01; 32]))
+        }
+
+        fn resumen(cargo:&str, candidatos:Vec<(&str, &str, i16)>, votos_emitidos:u32) -> sistema_de_votacion::sistema_de_votacion::ResumenDeEleccionCerrada {
+            let candidatos = candidatos.into_iter().enumerate()
+                .map(|(i, (nombre, dni, votos))| sistema_de_votacion::sistema_de_votacion::Candidato::con_votos(persona(nombre, dni), i as i16 + 1, votos))
+                .collect();
+            sistema_de_votacion::sistema_de_votacion::ResumenDeEleccionCerrada::new(String::from(cargo), 10, candidatos, 10, 8, votos_emitidos)
+        }
+
+        #[test]
+        fn historial_sin_votos(){
+            let elecciones = vec![
+                (1, resumen("Presidente", Vec::new(), 0)),
+                (3, resumen("Tesorero", Vec::new(), 0)),
+            ];
+            let historial = Historial::new(elecciones, Some(3));
+            assert_eq!(historial.get_tendencia_de_participacion(), vec![(1, 0), (3, 0)]);
+            assert_eq!(historial.get_elecciones()[1].get_cargo(), String::from("Tesorero"));
+            assert_eq!(historial.get_margen_promedio(), 0);
+            assert!(historial.get_candidatos_repetidos().is_empty());
+            assert_eq!(historial.get_siguiente(), Some(3));
+        }
+
+        #[test]
+        fn historial_agrupa_candidatos_repetidos_por_dni(){
+            let elecciones = vec![
+                (1, resumen("Presidente", vec![("Ana", "111", 6), ("Luis", "222", 2)], 8)),
+                (2, resumen("Tesorero", vec![("Eva", "333", 4), ("Ana", "111", 4)], 8)),
+                (4, resumen("Vocal", vec![("Luis", "222", 0), ("Juan", "444", 0)], 0)),
+            ];
+            let historial = Historial::new(elecciones, None);
+            assert_eq!(historial.get_tendencia_de_participacion(), vec![(1, 10000), (2, 10000), (4, 0)]);
+            let repetidos = historial.get_candidatos_repetidos();
+            let nombres:Vec<String> = repetidos.iter().map(|repetido| repetido.get_nombre()).collect();
+            assert_eq!(nombres, vec![String::from("Ana"), String::from("Luis")]);
+            let ana:Vec<(i16, String, u32, u32, bool)> = repetidos[0].get_desempenios().iter()
+                .map(|d| (d.get_id_eleccion(), d.get_cargo(), d.get_votos(), d.get_porcentaje(), d.get_gano()))
+                .collect();
+            assert_eq!(ana, vec![
+                (1, String::from("Presidente"), 6, 7500, true),
+                (2, String::from("Tesorero"), 4, 5000, true),
+            ]);
+            let luis:Vec<(i16, bool)> = repetidos[1].get_desempenios().iter().map(|d| (d.get_id_eleccion(), d.get_gano())).collect();
+            assert_eq!(luis, vec![(1, false), (4, false)]);
+        }
+
+        #[test]
+        fn historial_margen_promedio_solo_con_votos(){
+            let elecciones = vec![
+                (1, resumen("Presidente", vec![("Ana", "111", 6), ("Luis", "222", 2)], 8)),
+                (2, resumen("Tesorero", vec![("Eva", "333", 4), ("Juan", "444", 4)], 10)),
+                (3, resumen("Vocal", vec![("Sol", "555", 0)], 0)),
+            ];
+            let historial = Historial::new(elecciones, None);
+            let margenes:Vec<u32> = historial.get_elecciones().iter().map(|elec| elec.get_margen_de_victoria()).collect();
+            assert_eq!(margenes, vec![5000, 0, 0]);
+            assert_eq!(historial.get_margen_promedio(), 2500);
+            let cantidades:Vec<u32> = historial.get_elecciones().iter().map(|elec| elec.get_cantidad_de_candidatos()).collect();
+            assert_eq!(cantidades, vec![2, 2, 1]);
+        }
+    }
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink::env::call::FromAccountId;
+        use ink_e2e::ContractsBackend;
+        use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn resumen_guardado_al_consultar_una_eleccion_finalizada<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let mut constructor = SistemaDeVotacionRef::new();
+            let sistema = client.instantiate("sistema_de_votacion", &ink_e2e::alice(), &mut constructor)
+                .submit().await.expect("no se pudo instanciar el sistema");
+            let mut llamar_al_sistema = sistema.call_builder::<SistemaDeVotacion>();
+            let mut constructor = ReporteRef::new(SistemaDeVotacionRef::from_account_id(sistema.account_id));
+            let reporte = client.instantiate("reporte", &ink_e2e::alice(), &mut constructor)
+                .submit().await.expect("no se pudo instanciar el reporte");
+            let mut llamar_al_reporte = reporte.call_builder::<Reporte>();
+
+            client.call(&ink_e2e::alice(), &llamar_al_sistema.aprobar_reporte(1)).submit().await.expect("no se pudo aprobar el reporte");
+            for cargo in ["Vocal", "Presidente"]{//ya cerradas
+                client.call(&ink_e2e::alice(), &llamar_al_sistema.crear_eleccion(String::from(cargo), 1, 1, 2024, 2, 1, 2024))
+                    .submit().await.expect("no se pudo crear la eleccion");
+            }
+            let resumen = client.call(&ink_e2e::alice(), &llamar_al_reporte.resumen_de_eleccion(2)).dry_run().await?;
+            assert_eq!(resumen.return_value().unwrap_err(), String::from("La eleccion no esta finalizada"));
+            client.call(&ink_e2e::alice(), &llamar_al_sistema.finalizar_eleccion(2)).submit().await.expect("no se pudo finalizar la eleccion");
+
+            //una eleccion sin finalizar no se guarda, la finalizada se guarda con la primera consulta
+            let resultado = client.call(&ink_e2e::alice(), &llamar_al_reporte.reporte_de_resultado(1))
+                .submit().await.expect("no se pudo consultar el resultado").return_value().unwrap();
+            assert_eq!(resultado.get_cargo(), String::from("Vocal"));
+            let guardado = client.call(&ink_e2e::alice(), &llamar_al_reporte.tiene_resumen_guardado(1)).dry_run().await?;
+            assert_eq!(guardado.return_value(), Ok(false));
+            let resultado = client.call(&ink_e2e::alice(), &llamar_al_reporte.reporte_de_resultado(2))
+                .submit().await.expect("no se pudo consultar el resultado").return_value().unwrap();
+            assert_eq!(resultado.get_cargo(), String::from("Presidente"));
+            let guardado = client.call(&ink_e2e::alice(), &llamar_al_reporte.tiene_resumen_guardado(2)).dry_run().await?;
+            assert_eq!(guardado.return_value(), Ok(true));
+
+            let resumen = client.call(&ink_e2e::alice(), &llamar_al_reporte.resumen_de_eleccion(2)).dry_run().await?.return_value().unwrap();
+            assert_eq!(resumen.get_resultado(), resultado);
+            let hash = client.call(&ink_e2e::alice(), &llamar_al_sistema.hash_del_resultado(2)).dry_run().await?;
+            assert_eq!(hash.return_value(), Ok(Some(resumen.get_hash_del_resultado())));
+            let participacion = client.call(&ink_e2e::alice(), &llamar_al_reporte.reporte_de_participacion(2)).dry_run().await?;
+            assert_eq!(participacion.return_value(), Ok(resumen.get_participacion()));
+
+            //el id de una eleccion finalizada no cambia, asi que el resumen guardado sigue valiendo
+            let eliminar = client.call(&ink_e2e::alice(), &llamar_al_sistema.eliminar_eleccion(1)).dry_run().await?;
+            assert_eq!(eliminar.return_value(), Err(String::from("Hay una eleccion finalizada despues de esta, eliminarla cambiaria su id")));
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reporte_aprobado_solo_responde_a_usuarios_autorizados<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let mut constructor = SistemaDeVotacionRef::new();
+            let sistema = client.instantiate("sistema_de_votacion", &ink_e2e::alice(), &mut constructor)
+                .submit().await.expect("no se pudo instanciar el sistema");
+            let mut llamar_al_sistema = sistema.call_builder::<SistemaDeVotacion>();
+            let mut constructor = ReporteRef::new(SistemaDeVotacionRef::from_account_id(sistema.account_id));
+            let reporte = client.instantiate("reporte", &ink_e2e::alice(), &mut constructor)
+                .submit().await.expect("no se pudo instanciar el reporte");
+            let mut llamar_al_reporte = reporte.call_builder::<Reporte>();
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let no_autorizado = Err(String::from("El usuario no esta autorizado a consultar el reporte"));
+
+            //el constructor ya pidio el acceso
+            let estado = client.call(&ink_e2e::alice(), &llamar_al_reporte.estado_de_acceso()).dry_run().await?;
+            assert_eq!(estado.return_value(), EstadoDeReporte::Pendiente);
+            client.call(&ink_e2e::alice(), &llamar_al_sistema.aprobar_reporte(1)).submit().await.expect("no se pudo aprobar el reporte");
+            let estado = client.call(&ink_e2e::alice(), &llamar_al_reporte.estado_de_acceso()).dry_run().await?;
+            assert_eq!(estado.return_value(), EstadoDeReporte::Aprobado);
+            client.call(&ink_e2e::alice(), &llamar_al_sistema.crear_eleccion(String::from("Presidente"), 1, 1, 2024, 2, 1, 2024))
+                .submit().await.expect("no se pudo crear la eleccion");
+
+            let resultado = client.call(&ink_e2e::alice(), &llamar_al_reporte.reporte_de_resultado(1)).dry_run().await?;
+            assert_eq!(resultado.return_value().unwrap().get_cargo(), String::from("Presidente"));
+            let resultado = client.call(&ink_e2e::bob(), &llamar_al_reporte.reporte_de_resultado(1)).dry_run().await?;
+            assert_eq!(resultado.return_value().map(|resultado| resultado.get_cargo()), no_autorizado);
+
+            let autorizar = client.call(&ink_e2e::bob(), &llamar_al_reporte.autorizar_usuario(bob)).dry_run().await?;
+            assert_eq!(autorizar.return_value(), Err(String::from("No tiene permiso de administrador del reporte")));
+            client.call(&ink_e2e::alice(), &llamar_al_reporte.autorizar_usuario(bob)).submit().await.expect("no se pudo autorizar al usuario");
+            let autorizar = client.call(&ink_e2e::alice(), &llamar_al_reporte.autorizar_usuario(bob)).dry_run().await?;
+            assert_eq!(autorizar.return_value(), Err(String::from("El usuario ya esta autorizado")));
+            let resultado = client.call(&ink_e2e::bob(), &llamar_al_reporte.reporte_de_resultado(1)).dry_run().await?;
+            assert_eq!(resultado.return_value().unwrap().get_cargo(), String::from("Presidente"));
+            let resultado = client.call(&ink_e2e::charlie(), &llamar_al_reporte.reporte_de_resultado(1)).dry_run().await?;
+            assert_eq!(resultado.return_value().map(|resultado| resultado.get_cargo()), no_autorizado);
+
+            client.call(&ink_e2e::alice(), &llamar_al_reporte.revocar_usuario(bob)).submit().await.expect("no se pudo revocar al usuario");
+            let revocar = client.call(&ink_e2e::alice(), &llamar_al_reporte.revocar_usuario(bob)).dry_run().await?;
+            assert_eq!(revocar.return_value(), Err(String::from("El usuario no esta autorizado")));
+            let resultado = client.call(&ink_e2e::bob(), &llamar_al_reporte.reporte_de_resultado(1)).dry_run().await?;
+            assert_eq!(resultado.return_value().map(|resultado| resultado.get_cargo()), no_autorizado);
+            let autorizados = client.call(&ink_e2e::alice(), &llamar_al_reporte.obtener_usuarios_autorizados()).dry_run().await?;
+            assert_eq!(autorizados.return_value().1, Vec::<AccountId>::new());
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn reporte_sin_aprobar_devuelve_el_estado_de_su_pedido<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let mut constructor = SistemaDeVotacionRef::new();
+            let sistema = client.instantiate("sistema_de_votacion", &ink_e2e::alice(), &mut constructor)
+                .submit().await.expect("no se pudo instanciar el sistema");
+            let mut llamar_al_sistema = sistema.call_builder::<SistemaDeVotacion>();
+            let mut constructor = ReporteRef::new(SistemaDeVotacionRef::from_account_id(sistema.account_id));
+            let reporte = client.instantiate("reporte", &ink_e2e::alice(), &mut constructor)
+                .submit().await.expect("no se pudo instanciar el reporte");
+            let mut llamar_al_reporte = reporte.call_builder::<Reporte>();
+            client.call(&ink_e2e::alice(), &llamar_al_sistema.crear_eleccion(String::from("Presidente"), 1, 1, 2024, 2, 1, 2024))
+                .submit().await.expect("no se pudo crear la eleccion");
+
+            let resultado = client.call(&ink_e2e::alice(), &llamar_al_reporte.reporte_de_resultado(1)).dry_run().await?;
+            assert_eq!(resultado.return_value().map(|resultado| resultado.get_cargo()),
+                Err(String::from("El pedido de acceso del reporte esta pendiente de aprobacion por el administrador del sistema")));
+
+            client.call(&ink_e2e::alice(), &llamar_al_sistema.rechazar_reporte(1)).submit().await.expect("no se pudo rechazar el reporte");
+            let estado = client.call(&ink_e2e::alice(), &llamar_al_reporte.estado_de_acceso()).dry_run().await?;
+            assert_eq!(estado.return_value(), EstadoDeReporte::Rechazado);
+            let resultado = client.call(&ink_e2e::alice(), &llamar_al_reporte.reporte_de_resultado(1)).dry_run().await?;
+            assert_eq!(resultado.return_value().map(|resultado| resultado.get_cargo()),
+                Err(String::from("El pedido de acceso del reporte fue rechazado por el administrador del sistema")));
+            //un reporte rechazado no vuelve a la cola de pendientes
+            let pedido = client.call(&ink_e2e::alice(), &llamar_al_reporte.solicitar_acceso()).dry_run().await?;
+            assert!(pedido.return_value().is_err());
+            Ok(())
+        }
+    }
+
 }
\ No newline at end of file
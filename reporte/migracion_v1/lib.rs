@@ -0,0 +1,113 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+/// Contrato intermedio para actualizar un SistemaDeVotacion con datos guardados en el formato de la version 1.
+/// - ink decodifica la raiz antes de cada mensaje, asi que el codigo actual no puede leer esos datos. Este contrato
+///   tiene como raiz los tipos de la version 1, los convierte al formato actual y cambia el codigo por el del sistema.
+/// - Pasos: cambiar el codigo del contrato viejo por el de este (la version 1 no tiene actualizar_codigo, se hace con
+///   set_code de pallet-contracts), llamar a migrar con el code hash del sistema actual y despues a
+///   migrar_almacenamiento del sistema hasta que devuelva true.
+#[ink::contract]
+pub mod migracion_v1 {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+    use sistema_de_votacion::sistema_de_votacion::version_1::{PersonaV1, SistemaDeVotacionV1};
+
+    #[ink(storage)]
+    pub struct MigracionV1{
+        anterior:SistemaDeVotacionV1,//la raiz que guardo el codigo de la version 1, tal cual esta.
+    }
+    impl MigracionV1 {
+        /// Solo existe porque ink exige un constructor, este codigo se usa reemplazando el de un sistema ya desplegado.
+        #[ink(constructor)]
+        #[allow(clippy::new_without_default)]
+        pub fn new() -> Self {
+            let admin = PersonaV1{nombre:String::from("admin"), apellido:String::from("admin"), dni:String::from("admin"), accountid:Self::env().caller()};
+            Self{ anterior:SistemaDeVotacionV1{admin, reporte_sin_permiso:Vec::new(), reportes_con_permiso:Vec::new(), usuarios_registrados:Vec::new(), elecciones:Vec::new()} }
+        }
+
+        /// - Convierte los datos de la version 1 al formato actual y cambia el codigo por el del sistema de votacion.
+        /// - Solo lo puede hacer el administrador guardado en los datos de la version 1.
+        /// - Recibe &self para que ink no vuelva a guardar la raiz vieja encima de la convertida al terminar.
+        #[ink(message)]
+        pub fn migrar(&self, code_hash:Hash)->Result<(), String>{
+            self.convertir()?;
+            match self.env().set_code_hash(&code_hash){
+                Ok(()) => Ok(()),
+                Err(_) => Err(String::from("No se pudo actualizar el codigo del contrato")),
+            }
+        }
+
+        /// Reemplaza la raiz de la version 1 por la del formato actual, separado de migrar porque el entorno de
+        /// pruebas no puede cambiar el codigo.
+        fn convertir(&self)->Result<(), String>{
+            SistemaDeVotacion::migrar_desde_version_1()
+        }
+
+        /// Devuelve el administrador guardado en los datos de la version 1, el unico que puede migrar.
+        #[ink(message)]
+        pub fn administrador(&self)->AccountId{
+            self.anterior.admin.accountid
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::prelude::vec;
+        use sistema_de_votacion::sistema_de_votacion::EstadoDeReporte;
+        use sistema_de_votacion::sistema_de_votacion::version_1::{CandidatoV1, EleccionV1, UsuarioV1, VotanteV1};
+
+        #[ink::test]
+        fn solo_el_admin_de_la_version_1_migra(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let migracion = MigracionV1::new();
+            let raiz = <MigracionV1 as ink::storage::traits::StorageKey>::KEY;
+            ink::env::set_contract_storage(&raiz, &migracion.anterior);
+            assert_eq!(migracion.administrador(),accounts.alice);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(migracion.migrar([0x01; 32].into()),Err(String::from("No tiene permiso de admin")));
+            assert!(ink::env::get_contract_storage::<_, SistemaDeVotacionV1>(&raiz).unwrap().is_some());
+        }
+
+        #[ink::test]
+        fn el_admin_de_la_version_1_convierte_los_datos(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let persona = |nombre:&str, dni:&str, accountid:AccountId| PersonaV1{nombre:String::from(nombre), apellido:String::from("Perez"), dni:String::from(dni), accountid};
+            let migracion = MigracionV1{anterior:SistemaDeVotacionV1{
+                admin:persona("admin", "admin", accounts.alice),
+                reporte_sin_permiso:vec![accounts.eve],
+                reportes_con_permiso:vec![accounts.frank],
+                usuarios_registrados:vec![
+                    UsuarioV1{datos:persona("Carlos", "7654456", accounts.bob), participacion:vec![true]},
+                    UsuarioV1{datos:persona("Ana", "1245623", accounts.charlie), participacion:vec![true]},
+                ],
+                elecciones:vec![EleccionV1{
+                    cargo:String::from("Presidente"), inicio:1728950400000, fin:1732147199000,
+                    postulados_a_votantes:Vec::new(),
+                    votantes:vec![VotanteV1{dato:persona("Carlos", "7654456", accounts.bob), estado_del_voto:true}],
+                    postulados_a_candidatos:Vec::new(),
+                    candidatos:vec![CandidatoV1{dato:persona("Ana", "1245623", accounts.charlie), cant_votos:1}],
+                }],
+            }};
+            let raiz = <MigracionV1 as ink::storage::traits::StorageKey>::KEY;
+            ink::env::set_contract_storage(&raiz, &migracion.anterior);
+            assert_eq!(migracion.convertir(),Ok(()));
+            assert!(ink::env::get_contract_storage::<_, SistemaDeVotacionV1>(&raiz).is_err());
+
+            let mut sistema = ink::env::get_contract_storage::<_, SistemaDeVotacion>(&raiz).unwrap().unwrap();
+            assert_eq!(sistema.version_de_almacenamiento(),(1,true));
+            assert_eq!(sistema.migrar_almacenamiento(10),Ok(true));
+            assert_eq!(sistema.version_de_almacenamiento(),(2,false));
+            assert_eq!(sistema.estado_de_reporte(accounts.eve),EstadoDeReporte::Pendiente);
+            assert_eq!(sistema.estado_de_reporte(accounts.frank),EstadoDeReporte::Aprobado);
+            let elecciones = sistema.obtener_todas_las_elecciones().unwrap();
+            assert_eq!(elecciones.len(),1);
+            assert_eq!(elecciones[0].get_cargo(),String::from("Presidente"));
+            assert_eq!(elecciones[0].get_votantes().len(),1);
+            assert_eq!(elecciones[0].get_candidatos().len(),1);
+        }
+    }
+}
\ No newline at end of file
@@ -5,6 +5,8 @@ pub mod sistema_de_votacion {
     use ink::prelude::string::ToString;
     use ink::prelude::string::String;   
     use ink::prelude::vec::Vec;
+    use ink::env::hash::{Blake2x256, HashOutput};
+    use ink::storage::Mapping;
     
     #[derive(scale::Decode, scale::Encode,Debug,Default,Clone)]
     #[cfg_attr(
@@ -13,39 +15,353 @@ pub mod sistema_de_votacion {
     )]
     pub struct Eleccion{
         cargo:String,//se detalla el cargo que sera elegido en esta eleccion, informacion que puede ser relevante para el reporte.
+        cargos:Vec<String>,//todos los cargos que se eligen en la misma boleta, el primero es el cargo con el que se creo la eleccion.
         inicio:i64,
         fin:i64,
         postulados_a_votantes:Vec<Votante>,
         votantes:Vec<Votante>,
         postulados_a_candidatos:Vec<Candidato>,
         candidatos:Vec<Candidato>,
+        proximo_numero_de_boleta:i16,//numero que se le asignara al proximo candidato que se postule.
+        listas:Vec<Lista>,
+        tipo:TipoDeEleccion,//en un referendum el cargo es la pregunta que se vota.
+        opciones:Vec<Opcion>,
+        regla_de_mayoria:ReglaDeMayoria,
+        rechazados:Vec<Rechazo>,//postulaciones rechazadas, se conservan aunque la apelacion se acepte.
+        regla_de_validacion:Option<ReglaDeValidacion>,
+        pausa:Option<Pausa>,//mientras este en Some no se aceptan postulaciones ni votos.
+        deposito:Balance,//monto que debe pagar cada candidato al postularse, 0 si es gratis.
+        minimo_para_reintegro:u16,//porcentaje minimo de votos, en puntos basicos, para recuperar el deposito.
+        depositos:Vec<Deposito>,
+        votos_en_blanco:i16,
+        resultado_final:Option<ResultadoFinal>,//se guarda al finalizar la eleccion y ya no cambia.
+        hash_del_resultado:Option<Hash>,//blake2x256 del resultado final codificado en SCALE.
+        auditores:Vec<AccountId>,//cuentas de la junta electoral que certifican el resultado final.
+        umbral_de_certificacion:u16,//cantidad de certificaciones necesarias para que quede certificada.
+        firmantes:Vec<AccountId>,//auditores que certificaron el resultado.
+        disputas:Vec<Disputa>,
+        clave:u32,//identifica a la eleccion en los mapas del sistema, no cambia aunque se eliminen otras elecciones.
+        cantidad_de_comprobantes:u32,//los comprobantes se guardan en el sistema, con la clave de la eleccion.
+        acumulador_de_comprobantes:Hash,//hash encadenado de todos los comprobantes en orden.
+        intervalo_de_votos:u64,//milisegundos que abarca cada intervalo de la linea de tiempo de votos.
+        votos_por_intervalo:Vec<(u64, u32)>,//inicio de cada intervalo con votos y cuantos votos hubo, en orden. no se guarda quien voto.
     }
 
+    /// Intervalo de la linea de tiempo de votos si el administrador no configura otro, una hora.
+    const INTERVALO_DE_VOTOS_POR_DEFECTO:u64 = 3_600_000;
+    /// Intervalo minimo de la linea de tiempo de votos, con menos se podria deducir cuando voto cada votante.
+    const MIN_INTERVALO_DE_VOTOS:u64 = 60_000;
+
     impl Eleccion{
         pub fn new(cargo:String,inicio:&i64, fin:&i64)->Self{
-            Self{cargo,inicio:*inicio,fin:*fin,postulados_a_votantes:Vec::new(),postulados_a_candidatos:Vec::new(),votantes:Vec::new(),candidatos:Vec::new()}
+            Self{cargos:Vec::from([cargo.clone()]),cargo,inicio:*inicio,fin:*fin,postulados_a_votantes:Vec::new(),postulados_a_candidatos:Vec::new(),votantes:Vec::new(),candidatos:Vec::new(),proximo_numero_de_boleta:1,listas:Vec::new(),tipo:TipoDeEleccion::Candidatos,opciones:Vec::new(),regla_de_mayoria:ReglaDeMayoria::Simple,rechazados:Vec::new(),regla_de_validacion:None,pausa:None,deposito:0,minimo_para_reintegro:0,depositos:Vec::new(),votos_en_blanco:0,resultado_final:None,hash_del_resultado:None,auditores:Vec::new(),umbral_de_certificacion:0,firmantes:Vec::new(),disputas:Vec::new(),clave:0,cantidad_de_comprobantes:0,acumulador_de_comprobantes:Hash::default(),intervalo_de_votos:INTERVALO_DE_VOTOS_POR_DEFECTO,votos_por_intervalo:Vec::new()}
         }
 
+        #[allow(clippy::let_and_return)]
         pub fn get_postulados_a_votantes(&self)->Vec<Votante>{
             let postulados_votantes=self.postulados_a_votantes.clone();
             postulados_votantes
         }
+        #[allow(clippy::let_and_return)]
         pub fn get_votantes(&self)->Vec<Votante>{
             let votantes=self.votantes.clone();
             votantes
         }
         pub fn get_cantidad_de_votantes(&self)->i16{
-            self.votantes.len() as i16
+            match &self.resultado_final{
+                Some(resultado) => resultado.cantidad_de_votantes,
+                None => self.votantes.len() as i16,
+            }
         }
+        /// Cuenta a todos los que se postularon como votantes: pendientes, aprobados y rechazados.
+        /// Los rechazados con la apelacion aceptada ya estan entre los aprobados, no se cuentan dos veces.
+        pub fn get_cantidad_de_postulados_a_votantes(&self)->u32{
+            let rechazados = self.rechazados.iter()
+                .filter(|rechazo| rechazo.get_rol() == Rol::Votante && rechazo.apelacion != EstadoDeApelacion::Aceptada)
+                .count() as u32;
+            (self.postulados_a_votantes.len() as u32).checked_add(rechazados).unwrap().checked_add(self.get_cantidad_de_votantes().max(0) as u32).unwrap()
+        }
+        /// Cuenta los votantes que ya votaron, ya sea a un candidato, a una lista, a una opcion o en blanco.
         pub fn get_cantidad_de_votos_emitidos(&self)->i16{
-            let mut cantidad:i16=0;
-            for i in &self.candidatos{
-                cantidad = cantidad.checked_add(i.cant_votos).unwrap();
+            match &self.resultado_final{
+                Some(resultado) => resultado.votos_emitidos,
+                None => self.votantes.iter().filter(|v| v.estado_del_voto).count() as i16,
+            }
+        }
+        pub fn get_votos_en_blanco(&self)->i16{
+            match &self.resultado_final{
+                Some(resultado) => resultado.votos_en_blanco,
+                None => self.votos_en_blanco,
             }
-            cantidad
         }
+        /// Una vez finalizada, los candidatos son los del resultado final.
         pub fn get_candidatos(&self)->Vec<Candidato>{
-            self.candidatos.clone()
+            self.candidatos_vigentes().to_vec()
+        }
+        pub fn get_resultado_final(&self)->Option<ResultadoFinal>{
+            self.resultado_final.clone()
+        }
+        pub fn get_hash_del_resultado(&self)->Option<Hash>{
+            self.hash_del_resultado
+        }
+        pub fn get_cantidad_de_comprobantes(&self)->u32{
+            self.cantidad_de_comprobantes
+        }
+        pub fn get_intervalo_de_votos(&self)->u64{
+            self.intervalo_de_votos
+        }
+        /// Devuelve el inicio de cada intervalo en el que hubo votos y cuantos hubo, los intervalos sin votos no aparecen.
+        pub fn get_votos_por_intervalo(&self)->Vec<(u64, u32)>{
+            self.votos_por_intervalo.clone()
+        }
+        pub fn get_acumulador_de_comprobantes(&self)->Hash{
+            self.acumulador_de_comprobantes
+        }
+        pub fn get_auditores(&self)->Vec<AccountId>{
+            self.auditores.clone()
+        }
+        pub fn get_umbral_de_certificacion(&self)->u16{
+            self.umbral_de_certificacion
+        }
+        pub fn get_firmantes(&self)->Vec<AccountId>{
+            self.firmantes.clone()
+        }
+        pub fn get_disputas(&self)->Vec<Disputa>{
+            self.disputas.clone()
+        }
+        /// Una eleccion finalizada queda certificada al llegar al umbral de firmantes, aunque algun auditor la haya disputado.
+        pub fn get_estado_de_certificacion(&self)->EstadoDeCertificacion{
+            if self.resultado_final.is_none(){
+                EstadoDeCertificacion::SinFinalizar
+            }else if self.umbral_de_certificacion>0 && self.firmantes.len() >= self.umbral_de_certificacion as usize{
+                EstadoDeCertificacion::Certificada
+            }else if !self.disputas.is_empty(){
+                EstadoDeCertificacion::Disputada
+            }else{
+                EstadoDeCertificacion::Pendiente
+            }
+        }
+        fn candidatos_vigentes(&self)->&[Candidato]{
+            match &self.resultado_final{
+                Some(resultado) => &resultado.candidatos,
+                None => &self.candidatos,
+            }
+        }
+        fn opciones_vigentes(&self)->&[Opcion]{
+            match &self.resultado_final{
+                Some(resultado) => &resultado.opciones,
+                None => &self.opciones,
+            }
+        }
+        fn listas_vigentes(&self)->&[Lista]{
+            match &self.resultado_final{
+                Some(resultado) => &resultado.listas,
+                None => &self.listas,
+            }
+        }
+        pub fn get_inicio(&self)->i64{
+            self.inicio
+        }
+        pub fn get_fin(&self)->i64{
+            self.fin
+        }
+        /// Devuelve el estado de la eleccion en el timestamp ahora.
+        pub fn get_estado(&self, ahora:u64)->EstadoDeEleccion{
+            if ahora < self.inicio as u64{
+                EstadoDeEleccion::NoIniciada
+            }else if ahora > self.fin as u64{
+                EstadoDeEleccion::Cerrada
+            }else{
+                EstadoDeEleccion::Abierta
+            }
+        }
+        pub fn get_cargo(&self)->String{
+            self.cargo.clone()
+        }
+        pub fn get_cargos(&self)->Vec<String>{
+            self.cargos.clone()
+        }
+        /// Arma la vista publica de la eleccion en el timestamp ahora.
+        pub fn get_vista(&self, ahora:u64)->VistaDeEleccion{
+            let mut candidatos:Vec<FichaDeCandidato> = self.candidatos.iter().map(|c| c.get_ficha()).collect();
+            candidatos.sort_unstable_by_key(|f| f.numero_de_boleta);
+            VistaDeEleccion{
+                cargo:self.cargo.clone(),
+                cargos:self.cargos.clone(),
+                tipo:self.tipo,
+                inicio:self.inicio,
+                fin:self.fin,
+                estado:self.get_estado(ahora),
+                candidatos,
+                listas:self.listas.iter().map(|l| (l.nombre.clone(), l.candidatos.clone())).collect(),
+                opciones:self.opciones.iter().map(|o| o.texto.clone()).collect(),
+                cantidad_postulados_a_votantes:self.postulados_a_votantes.len() as u32,
+                cantidad_votantes_aprobados:self.votantes.len() as u32,
+            }
+        }
+        /// Arma el resumen compacto que usan los reportes historicos, sin padrones ni perfiles de candidatos.
+        fn armar_resumen_cerrado(&self)->ResumenDeEleccionCerrada{
+            let aprobados = self.get_cantidad_de_votantes().max(0) as u32;
+            ResumenDeEleccionCerrada{
+                cargos:self.cargos.clone(),
+                tipo:self.tipo,
+                fin:self.fin,
+                candidatos:self.candidatos_vigentes().iter()
+                    .map(|c| Candidato{perfil:PerfilCandidato::default(), ..c.clone()})
+                    .collect(),
+                opciones:self.get_opciones(),
+                postulados_a_votantes:self.get_cantidad_de_postulados_a_votantes(),
+                votantes_aprobados:aprobados,
+                votos_emitidos:self.get_cantidad_de_votos_emitidos().max(0) as u32,
+            }
+        }
+        /// El candidato recupera el deposito si fue aprobado y alcanzo el minimo de votos de la eleccion.
+        /// Los votos a una lista cuentan para cada candidato que la integra.
+        fn corresponde_reintegro(&self, dato:&Persona)->bool{
+            match self.candidatos.iter().find(|c| c.dato == *dato){
+                Some(candidato) => {
+                    let votos_de_listas = self.listas.iter()
+                        .filter(|l| l.candidatos.contains(&candidato.numero_de_boleta))
+                        .fold(0u64, |total, l| total.checked_add(l.cant_votos as u64).unwrap());
+                    let votos = (candidato.cant_votos as u64).checked_add(votos_de_listas).unwrap().checked_mul(10000).unwrap();
+                    let minimo = (self.minimo_para_reintegro as u64).checked_mul(self.get_cantidad_de_votos_emitidos() as u64).unwrap();
+                    votos >= minimo
+                }
+                None => false,
+            }
+        }
+        /// Arma el resultado final con los datos actuales de la eleccion.
+        fn armar_resultado_final(&self, ahora:u64)->ResultadoFinal{
+            let ganadores = match self.tipo{
+                TipoDeEleccion::Referendum => Vec::from([self.get_opcion_ganadora()]),
+                TipoDeEleccion::Candidatos => (1..=self.cargos.len() as i16).map(|id_cargo| {
+                    let candidatos = self.get_candidatos_de_cargo(id_cargo);
+                    let maximo = candidatos.iter().map(|c| c.cant_votos).max()?;
+                    if candidatos.iter().filter(|c| c.cant_votos == maximo).count()>1{
+                        return None;
+                    }
+                    candidatos.iter().find(|c| c.cant_votos == maximo).map(|c| c.numero_de_boleta)
+                }).collect(),
+            };
+            ResultadoFinal{
+                cargos:self.cargos.clone(),
+                candidatos:self.candidatos.clone(),
+                listas:self.listas.clone(),
+                opciones:self.opciones.clone(),
+                cantidad_de_votantes:self.get_cantidad_de_votantes(),
+                votos_emitidos:self.get_cantidad_de_votos_emitidos(),
+                votos_en_blanco:self.votos_en_blanco,
+                ganadores,
+                finalizada_en:ahora,
+                cantidad_de_comprobantes:self.cantidad_de_comprobantes,
+                acumulador_de_comprobantes:self.acumulador_de_comprobantes,
+            }
+        }
+        /// Corre el fin de la eleccion lo que estuvo abierta entre desde y hasta, y devuelve cuanto lo corrio.
+        /// Una eleccion que no habia empezado o ya habia terminado en ese intervalo no se extiende.
+        fn extender_fin(&mut self, desde:u64, hasta:u64)->u64{
+            let desde = desde.max(self.inicio.max(0) as u64);
+            let hasta = hasta.min(self.fin.max(0) as u64);
+            if self.resultado_final.is_some() || hasta <= desde{
+                return 0;
+            }
+            let extension = hasta.checked_sub(desde).unwrap();
+            self.fin = self.fin.checked_add(extension as i64).unwrap();
+            extension
+        }
+        /// Lleva la eleccion al formato de la version actual, se puede aplicar mas de una vez.
+        fn migrar(&mut self){
+            if self.cargos.is_empty(){
+                self.cargos.push(self.cargo.clone());
+            }
+            if self.proximo_numero_de_boleta<1{
+                self.proximo_numero_de_boleta = 1;
+            }
+            for candidato in self.postulados_a_candidatos.iter_mut().chain(self.candidatos.iter_mut()){
+                if candidato.numero_de_boleta<1{
+                    candidato.numero_de_boleta = self.proximo_numero_de_boleta;
+                    self.proximo_numero_de_boleta = self.proximo_numero_de_boleta.checked_add(1).unwrap();
+                }
+                if candidato.id_cargo<1{
+                    candidato.id_cargo = 1;
+                }
+            }
+        }
+        /// Busca a la persona en los vectores de la eleccion y devuelve su rol, su validacion y si ya voto.
+        fn get_participacion(&self, dato:&Persona)->Option<(Rol, EstadoDeValidacion, bool)>{
+            if self.postulados_a_votantes.iter().any(|v| v.dato == *dato){
+                return Some((Rol::Votante, EstadoDeValidacion::Postulado, false));
+            }
+            if let Some(votante) = self.votantes.iter().find(|v| v.dato == *dato){
+                return Some((Rol::Votante, EstadoDeValidacion::Aprobado, votante.estado_del_voto));
+            }
+            if self.postulados_a_candidatos.iter().any(|c| c.dato == *dato){
+                return Some((Rol::Candidato, EstadoDeValidacion::Postulado, false));
+            }
+            if self.candidatos.iter().any(|c| c.dato == *dato){
+                return Some((Rol::Candidato, EstadoDeValidacion::Aprobado, false));
+            }
+            if let Some(rechazo) = self.rechazados.iter().find(|r| *r.postulante.get_dato() == *dato){
+                return Some((rechazo.get_rol(), EstadoDeValidacion::Rechazado, false));
+            }
+            None
+        }
+        /// Devuelve los candidatos aprobados para el cargo, el id_cargo empieza en 1.
+        pub fn get_candidatos_de_cargo(&self, id_cargo:i16)->Vec<Candidato>{
+            self.candidatos_vigentes().iter().filter(|c| c.id_cargo == id_cargo).cloned().collect()
+        }
+        pub fn get_tipo(&self)->TipoDeEleccion{
+            self.tipo
+        }
+        pub fn get_opciones(&self)->Vec<Opcion>{
+            self.opciones_vigentes().to_vec()
+        }
+        pub fn get_regla_de_mayoria(&self)->ReglaDeMayoria{
+            self.regla_de_mayoria
+        }
+        /// Devuelve el id de la opcion ganadora del referendum segun su regla de mayoria, o None si ninguna la cumple.
+        pub fn get_opcion_ganadora(&self)->Option<i16>{
+            let opciones = self.opciones_vigentes();
+            let total:i64 = opciones.iter().map(|o| o.cant_votos as i64).sum();
+            let maximo = opciones.iter().map(|o| o.cant_votos).max()?;
+            if total==0 || opciones.iter().filter(|o| o.cant_votos==maximo).count()>1{
+                return None;
+            }
+            let cumple = match self.regla_de_mayoria{
+                ReglaDeMayoria::Simple => true,
+                ReglaDeMayoria::Absoluta => (maximo as i64).checked_mul(2).unwrap() > total,
+                ReglaDeMayoria::Calificada(puntos_basicos) => (maximo as i64).checked_mul(10_000).unwrap() >= total.checked_mul(puntos_basicos as i64).unwrap(),
+            };
+            if !cumple{
+                return None;
+            }
+            let posicion = opciones.iter().position(|o| o.cant_votos==maximo)?;
+            Some((posicion as i16).checked_add(1).unwrap())
+        }
+        pub fn get_deposito(&self)->Balance{
+            self.deposito
+        }
+        pub fn get_minimo_para_reintegro(&self)->u16{
+            self.minimo_para_reintegro
+        }
+        pub fn get_depositos(&self)->Vec<Deposito>{
+            self.depositos.clone()
+        }
+        pub fn get_pausa(&self)->Option<Pausa>{
+            self.pausa.clone()
+        }
+        pub fn get_rechazados(&self)->Vec<Rechazo>{
+            self.rechazados.clone()
+        }
+        pub fn get_listas(&self)->Vec<Lista>{
+            self.listas_vigentes().to_vec()
+        }
+        /// Devuelve los candidatos de la lista en el orden en que aparecen en ella.
+        pub fn get_candidatos_de_lista(&self, lista:&Lista)->Vec<Candidato>{
+            lista.candidatos.iter()
+                .filter_map(|numero| self.candidatos_vigentes().iter().find(|c| c.numero_de_boleta == *numero))
+                .cloned()
+                .collect()
         }
     }
     #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
@@ -61,12 +377,22 @@ pub mod sistema_de_votacion {
     }
 
     impl Persona{
-        fn new(nombre:String, apellido:String, dni:String, accountid:AccountId)->Self{
+        pub fn new(nombre:String, apellido:String, dni:String, accountid:AccountId)->Self{
             Self{nombre,apellido,dni,accountid}
         }
+        pub fn get_nombre(&self)->String{
+            self.nombre.clone()
+        }
+        pub fn get_apellido(&self)->String{
+            self.apellido.clone()
+        }
+        pub fn get_dni(&self)->String{
+            self.dni.clone()
+        }
     }
 
     
+    #[allow(clippy::empty_line_after_outer_attr)]
     #[derive(scale::Decode, scale::Encode,Debug,Clone)]
     #[cfg_attr(
         feature = "std",
@@ -99,6 +425,9 @@ pub mod sistema_de_votacion {
         pub fn new(dato:Persona)->Self{
             Self{dato,estado_del_voto:false}
         }
+        pub fn get_dato(&self)->Persona{
+            self.dato.clone()
+        }
     }
     
     #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
@@ -109,358 +438,2312 @@ pub mod sistema_de_votacion {
     pub struct Candidato{
         dato: Persona,
         cant_votos:i16,
+        numero_de_boleta:i16,//numero con el que aparece en la boleta, lo asigna el sistema al postularse.
+        perfil:PerfilCandidato,
+        id_cargo:i16,//posicion del cargo al que se postula dentro de la eleccion.
     }
     impl Candidato{
         pub fn new(dato:Persona)->Self{
-            Self{dato,cant_votos:0}
+            Self{dato,cant_votos:0,numero_de_boleta:0,perfil:PerfilCandidato::default(),id_cargo:1}
+        }
+        /// Arma un candidato del primer cargo con su numero de boleta y sus votos, por ejemplo para armar resultados fuera del sistema.
+        pub fn con_votos(dato:Persona, numero_de_boleta:i16, cant_votos:i16)->Self{
+            Self{dato,cant_votos,numero_de_boleta,perfil:PerfilCandidato::default(),id_cargo:1}
+        }
+        pub fn get_dato(&self)->Persona{
+            self.dato.clone()
         }
         pub fn get_cantidad_votos(&self)->i16{
             self.cant_votos
         }
-    }
-    #[ink(storage)]
-    pub struct SistemaDeVotacion{
-        admin:Persona,
-        reporte_sin_permiso:Vec<AccountId>,
-        reportes_con_permiso:Vec<AccountId>,
-        usuarios_registrados:Vec<Usuario>,//todos los usuarios regitrados en el sistema, pueden participar de una elecion o no
-        elecciones:Vec<Eleccion>,
-    }
-    impl SistemaDeVotacion {
-        /// - Instancia el sistema de votacion.
-        ///  - lo setea con valores iniciales y asigna el accountid del administrador.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let sistema = SistemaDeVotacion::new();
-        /// ```
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            Self { 
-                admin: Persona::new(String::from("admin"), String::from("admin"), String::from("admin"),Self::env().caller() ),
-                usuarios_registrados:Vec::new(),
-                elecciones:Vec::new(),
-                reporte_sin_permiso:Vec::new(),
-                reportes_con_permiso:Vec::new(),
+        pub fn get_numero_de_boleta(&self)->i16{
+            self.numero_de_boleta
+        }
+        pub fn get_perfil(&self)->PerfilCandidato{
+            self.perfil.clone()
+        }
+        pub fn get_id_cargo(&self)->i16{
+            self.id_cargo
+        }
+        /// Devuelve los datos publicos del candidato, sin dni ni accountid.
+        pub fn get_ficha(&self)->FichaDeCandidato{
+            FichaDeCandidato{
+                nombre:self.dato.nombre.clone(),
+                apellido:self.dato.apellido.clone(),
+                numero_de_boleta:self.numero_de_boleta,
+                perfil:self.perfil.clone(),
+                id_cargo:self.id_cargo,
             }
         }
-    
-        //METODOS ADMINISTRADOR
+    }
 
-        /// - Crea una eleccion y la agrega al sistema.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.crear_eleccion(String::from("un cargo"),1,1,2024,2,3,2024);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn crear_eleccion(&mut self, cargo: String, dia_inicio: i32, mes_inicio: i32, anio_inicio: i32, dia_fin: i32, mes_fin: i32, anio_fin: i32) -> Result<(), String> {
+    #[derive(scale::Decode, scale::Encode,Debug,Default,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum TipoDeEleccion{
+        #[default]
+        Candidatos,
+        Referendum,//no hay candidatos, se vota una de las opciones de la pregunta.
+    }
 
-            if Self::dias_en_mes(anio_inicio,mes_inicio)==0||Self::dias_en_mes(anio_inicio,mes_inicio)<dia_inicio||dia_inicio<0 {
-                return Err(String::from("Fecha de inicio invalida"));
-            }
+    /// Regla para decidir la opcion ganadora de un referendum.
+    #[derive(scale::Decode, scale::Encode,Debug,Default,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum ReglaDeMayoria{
+        #[default]
+        Simple,//gana la opcion mas votada, si hay empate no gana ninguna.
+        Absoluta,//la mas votada tiene que superar la mitad de los votos.
+        Calificada(u16),//la mas votada tiene que alcanzar los puntos basicos indicados (6667 son dos tercios).
+    }
 
-            if (Self::dias_en_mes(anio_fin,mes_fin)==0)||Self::dias_en_mes(anio_fin,mes_fin)<dia_fin||dia_fin<0{
-                return Err(String::from("Fecha de fin invalida"));
-            }
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoDeEleccion{
+        NoIniciada,
+        Abierta,
+        Cerrada,
+    }
 
-            let fecha_de_inicio = Self::timestamp(anio_inicio, mes_inicio, dia_inicio, 0, 0, 0,0);
-            let fecha_de_inicio = match fecha_de_inicio {
-                Ok(dato) => dato,
-                Err(_e) => return Err(String::from("No se puede convertir fecha de inicio ")),
-            };
-            let fecha_de_fin = Self::timestamp(anio_fin, mes_fin, dia_fin, 0, 0, 0,0);
-            let fecha_de_fin = match fecha_de_fin {
-                Ok(dato) => dato,
-                Err(_e) => return Err(String::from("No se puede convertir fecha de fin ")),
-                };
-        
-            if fecha_de_inicio >= fecha_de_fin {
-                return Err(String::from("La fecha de inicio debe ser anterior a la fecha de fin"));
-            }
-            if Self::env().caller()  != self.admin.accountid {
-                return Err(String::from("No tienes permiso para crear una elección"));
-            }
-        
-            let elec = Eleccion::new(cargo, &fecha_de_inicio, &fecha_de_fin);
-            self.elecciones.push(elec);
-            for e in self.usuarios_registrados.iter_mut() {
-                e.participacion.push(false);
-            }
-            Ok(())
-        }
+    /// Filtros opcionales para la consulta paginada de elecciones, los que son None no filtran.
+    #[derive(scale::Decode, scale::Encode,Debug,Default,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct FiltroDeElecciones{
+        estado:Option<EstadoDeEleccion>,
+        desde:Option<i64>,//la eleccion tiene que empezar en o despues de este timestamp.
+        hasta:Option<i64>,//la eleccion tiene que terminar en o antes de este timestamp.
+        cargo:Option<String>,//alguno de los cargos de la eleccion tiene que ser igual a este.
+    }
 
-        /// - Elimina una eleccion del sistema.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.eliminar_eleccion(1);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn eliminar_eleccion(&mut self, id_eleccion:i32) -> Result<(), String> {
+    impl FiltroDeElecciones{
+        pub fn new(estado:Option<EstadoDeEleccion>, desde:Option<i64>, hasta:Option<i64>, cargo:Option<String>)->Self{
+            Self{estado,desde,hasta,cargo}
+        }
+        ///Devuelve true si la eleccion cumple con todos los filtros.
+        fn cumple(&self, eleccion:&Eleccion, ahora:u64)->bool{
+            self.estado.is_none_or(|estado| eleccion.get_estado(ahora) == estado)
+                && self.desde.is_none_or(|desde| eleccion.inicio >= desde)
+                && self.hasta.is_none_or(|hasta| eleccion.fin <= hasta)
+                && self.cargo.as_ref().is_none_or(|cargo| eleccion.cargos.contains(cargo))
+        }
+    }
 
-            if id_eleccion as usize>self.elecciones.len()||id_eleccion<1{
-                return Err(String::from("Id de eleccion invalido"));
-            }
+    /// Vista publica de una eleccion para las aplicaciones de los votantes.
+    /// No incluye la identidad de los votantes ni los votos mientras la eleccion no cerro.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct VistaDeEleccion{
+        cargo:String,
+        cargos:Vec<String>,
+        tipo:TipoDeEleccion,
+        inicio:i64,
+        fin:i64,
+        estado:EstadoDeEleccion,
+        candidatos:Vec<FichaDeCandidato>,//ordenados por numero de boleta.
+        listas:Vec<(String, Vec<i16>)>,//nombre de cada lista con los numeros de boleta de sus candidatos.
+        opciones:Vec<String>,//textos de las opciones si es un referendum.
+        cantidad_postulados_a_votantes:u32,
+        cantidad_votantes_aprobados:u32,
+    }
 
-            if Self::env().caller()  != self.admin.accountid {
-                return Err(String::from("No tienes permiso para crear una elección"));
-            }
-        
-            self.elecciones.remove((id_eleccion.checked_sub(1).unwrap())as usize);
-            for e in self.usuarios_registrados.iter_mut() {
-                e.participacion.remove((id_eleccion.checked_sub(1).unwrap())as usize);
-            }
-            Ok(())
+    impl VistaDeEleccion{
+        pub fn get_cargo(&self)->String{
+            self.cargo.clone()
         }
-
-        ///Retorna true si el id de la eleccion es valida.
-        fn existe_eleccion(&self,id:i16)->bool{
-            if id==0{
-                false
-            }else{
-                self.elecciones.len()>=(id as usize)
-            }
+        pub fn get_cargos(&self)->Vec<String>{
+            self.cargos.clone()
         }
-
-        /// Retorna true si el id de usuario es valido.
-        fn existe_usuario(&self,id:i16)->bool{
-            if id==0{
-                false
-            }else{
-                self.usuarios_registrados.len()>=id as usize
-            }
+        pub fn get_tipo(&self)->TipoDeEleccion{
+            self.tipo
         }
-
-        /// Retorna true si la eleccion no ha empezado.
-        fn eleccion_no_empezada(&self,id:i16)->bool{
-            if id!=0 || self.elecciones.len()>=id as usize{
-                let eleccion=self.elecciones.get(id.checked_sub(1).unwrap() as usize).unwrap();
-                if Self::env().block_timestamp() < eleccion.inicio as u64{
-                    return true;
-                }
-            };
-            false
+        pub fn get_inicio(&self)->i64{
+            self.inicio
         }
-
-        /// - Recibe un AccountId y lo asigna como administrador.
-        /// - Funciona solo si el es el administrador quien lo llama, de caso contrario da error.
-        /// - EJEMPLO
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.ceder_admin(accounts.charlie);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn ceder_admin(&mut self, actid: AccountId) -> Result<(), String> {
-            if Self::env().caller() == self.admin.accountid {
-                self.admin.accountid=actid;
-                Ok(())
-            } else {
-                Err(String::from("No tiene permiso de admin para ejecutar este método."))
-            }
+        pub fn get_fin(&self)->i64{
+            self.fin
         }
+        pub fn get_estado(&self)->EstadoDeEleccion{
+            self.estado
+        }
+        pub fn get_candidatos(&self)->Vec<FichaDeCandidato>{
+            self.candidatos.clone()
+        }
+        pub fn get_listas(&self)->Vec<(String, Vec<i16>)>{
+            self.listas.clone()
+        }
+        pub fn get_opciones(&self)->Vec<String>{
+            self.opciones.clone()
+        }
+        pub fn get_cantidad_postulados_a_votantes(&self)->u32{
+            self.cantidad_postulados_a_votantes
+        }
+        pub fn get_cantidad_votantes_aprobados(&self)->u32{
+            self.cantidad_votantes_aprobados
+        }
+    }
 
-        /// - Retorna true si se pudo validar con exito, false en caso contrario.
-        /// - Valida solo si el usuario esta postulado para esa eleccion.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.validar_usuario(1,2,true);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn validar_usuario(&mut self, id_usuario:i16, id_eleccion:i16, valido:bool)->Result<(),String>{
-
-            if Self::env().caller() !=self.admin.accountid{
-                return Err(String::from("No tiene permiso de admin"));
-            }
-            
-            if !self.existe_eleccion(id_eleccion){
-                return Err(String::from("No existe una eleccion con ese id"));
-            }
-            
-            if !self.existe_usuario(id_usuario){
-                return Err(String::from("No existe usuario con ese id"));
-            }
-            
-            if !self.eleccion_no_empezada(id_eleccion){
-                return Err(String::from("Eleccio ya empezada, no se puede validar luego de empezar"));
-            } 
-
-            if !self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].participacion[id_eleccion.checked_sub(1).unwrap() as usize]{
-                return Err(String::from("IEl usuario no esta participando en la eleccion con ese id"));
-            }
+    /// Resumen de una eleccion cerrada para los reportes historicos.
+    /// Trae los votos de cada candidato u opcion y las cantidades del padron, pero no los padrones ni los perfiles.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo)
+    )]
+    pub struct ResumenDeEleccionCerrada{
+        cargos:Vec<String>,
+        tipo:TipoDeEleccion,
+        fin:i64,
+        candidatos:Vec<Candidato>,//sin perfil.
+        opciones:Vec<Opcion>,
+        postulados_a_votantes:u32,//pendientes, aprobados y rechazados como votantes.
+        votantes_aprobados:u32,
+        votos_emitidos:u32,
+    }
 
-            if valido {
-                let vot = Votante::new(self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone());
-                let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
-                if let Some(position) = eleccion.postulados_a_votantes.iter().position(|x| *x == vot.clone()) {
-                    eleccion.postulados_a_votantes.remove(position);
-                    eleccion.votantes.push(vot);
-                } else {
-                    let can = Candidato::new(self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone());
-                    if let Some(position) = eleccion.postulados_a_candidatos.iter().position(|x| *x == can.clone()) {
-                        eleccion.candidatos.push(can);
-                        eleccion.postulados_a_candidatos.remove(position);
-                    }
-                }
-            }
-            Ok(())
+    impl ResumenDeEleccionCerrada{
+        /// Arma el resumen de una eleccion de candidatos de un solo cargo, por ejemplo para probar reportes fuera del sistema.
+        pub fn new(cargo:String, fin:i64, candidatos:Vec<Candidato>, postulados_a_votantes:u32, votantes_aprobados:u32, votos_emitidos:u32)->Self{
+            Self{cargos:Vec::from([cargo]),tipo:TipoDeEleccion::Candidatos,fin,candidatos,opciones:Vec::new(),postulados_a_votantes,votantes_aprobados,votos_emitidos}
         }
-
-        /// - Aprueba un reporte que pidio permiso para acceder al sistema. el parametro es usado para acceder al permiso por orden de llegada.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.aprobar_reporte(3);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn aprobar_reporte(&mut self,id:i16)->Result<(), String>{
-            if self.reporte_sin_permiso.len()<id as usize{
-                return Err(String::from("El id proporcionado no es valido"));
-            }
-            if id > 0 &&Self::env().caller() != self.admin.accountid{
-                return Err(String::from("No tiene permiso de administrador"));
-            }
-            
-            let account = self.reporte_sin_permiso.remove((id.checked_sub(1).unwrap())as usize);
-            self.reportes_con_permiso.push(account);
-            Ok(())
+        pub fn get_cargo(&self)->String{
+            self.cargos.first().cloned().unwrap_or_default()
         }
-        
+        pub fn get_cargos(&self)->Vec<String>{
+            self.cargos.clone()
+        }
+        pub fn get_tipo(&self)->TipoDeEleccion{
+            self.tipo
+        }
+        pub fn get_fin(&self)->i64{
+            self.fin
+        }
+        pub fn get_candidatos(&self)->Vec<Candidato>{
+            self.candidatos.clone()
+        }
+        pub fn get_candidatos_de_cargo(&self, id_cargo:i16)->Vec<Candidato>{
+            self.candidatos.iter().filter(|c| c.id_cargo == id_cargo).cloned().collect()
+        }
+        pub fn get_opciones(&self)->Vec<Opcion>{
+            self.opciones.clone()
+        }
+        pub fn get_postulados_a_votantes(&self)->u32{
+            self.postulados_a_votantes
+        }
+        pub fn get_votantes_aprobados(&self)->u32{
+            self.votantes_aprobados
+        }
+        pub fn get_votos_emitidos(&self)->u32{
+            self.votos_emitidos
+        }
+    }
 
-        /// - Rechaza un reporte que pidio permiso para acceder al sistema. el parametro es usado para acceder al permiso por orden de llegada.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.rechazar_reporte(3);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn rechazar_reporte(&mut self,id:i16)->Result<(), String>{
-            if self.reporte_sin_permiso.len()<id as usize{
-                return Err(String::from("El id proporcionado no es valido"));
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Rol{
+        Votante,
+        Candidato,
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoDeValidacion{
+        Postulado,//todavia no fue validado por el administrador.
+        Aprobado,
+        Rechazado,
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum MotivoDeRechazo{
+        SinEspecificar,
+        DatosIncompletos,
+        NoHabilitado,//no cumple los requisitos para participar de la eleccion.
+        Duplicado,//la misma persona ya participa con otro usuario.
+        Otro,
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoDeApelacion{
+        SinApelar,
+        Pendiente,
+        Aceptada,//el usuario quedo aprobado en su rol.
+        Denegada,
+    }
+
+    /// Postulacion que fue rechazada, guarda el votante o el candidato tal como se postulo.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Postulante{
+        Votante(Votante),
+        Candidato(Candidato),
+    }
+
+    impl Postulante{
+        pub fn get_dato(&self)->&Persona{
+            match self{
+                Postulante::Votante(votante) => &votante.dato,
+                Postulante::Candidato(candidato) => &candidato.dato,
             }
-            if id > 0 &&Self::env().caller() != self.admin.accountid{
-                return Err(String::from("No tiene permiso de administrador"));
+        }
+        pub fn get_rol(&self)->Rol{
+            match self{
+                Postulante::Votante(_) => Rol::Votante,
+                Postulante::Candidato(_) => Rol::Candidato,
             }
-            
-            self.reporte_sin_permiso.remove((id.checked_sub(1).unwrap())as usize);
-            Ok(())
         }
+    }
 
-        /// - Agrega al sistema la peticion de permiso de un reporte para poder acceder.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.agregar_accountid_de_reporte(accounts.bob);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn agregar_accountid_de_reporte(&mut self,id:AccountId){
-            self.reporte_sin_permiso.push(id);
-        }
+    /// Cantidad maxima de decisiones que se procesan en una validacion por lote.
+    const MAX_TAMANIO_DE_LOTE:u32 = 100;
+    /// Cantidad maxima de dni en el padron de una regla de validacion.
+    const MAX_TAMANIO_DE_PADRON:usize = 500;
 
-        /// - Obtiene un usuario del sistema.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.obtener_usuario(1);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn obtener_usuario(&self, id_usuario:i16)->Option<Usuario>{
-            if Self::env().caller() ==self.admin.accountid && id_usuario<=(self.usuarios_registrados.len() as i16){
-                return Some(self.usuarios_registrados.get(id_usuario.checked_sub(1).unwrap() as usize).unwrap().clone());
+    /// Regla para aprobar de una vez todas las postulaciones pendientes de una eleccion.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum ReglaDeValidacion{
+        Todos,
+        Rol(Rol),//solo los postulados con ese rol.
+        PrefijoDeDni(String),//solo los dni que empiezan con el prefijo.
+        Padron(Vec<String>),//solo los dni que estan en el padron.
+    }
+
+    impl ReglaDeValidacion{
+        fn cumple(&self, dato:&Persona, rol:Rol)->bool{
+            match self{
+                ReglaDeValidacion::Todos => true,
+                ReglaDeValidacion::Rol(regla) => *regla == rol,
+                ReglaDeValidacion::PrefijoDeDni(prefijo) => dato.dni.starts_with(prefijo.as_str()),
+                ReglaDeValidacion::Padron(padron) => padron.contains(&dato.dni),
             }
-            None
         }
+    }
 
-        ///Devuelve una eleccion, util para el reporte.
-        #[ink(message)]
-        pub fn obtener_eleccion(&self, eleccion_id:i16)->Option<Eleccion>{
-            if Self::env().caller() ==self.admin.accountid && eleccion_id!=0 && eleccion_id<=self.elecciones.len() as i16{
-                let elec = self.elecciones.get(eleccion_id.checked_sub(1).unwrap() as usize).unwrap();
-                return Some(elec.clone())
-            }
-            None
+    /// Largo maximo en bytes del descargo de una apelacion.
+    const MAX_LARGO_DESCARGO:usize = 512;
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Rechazo{
+        postulante:Postulante,
+        motivo:MotivoDeRechazo,
+        apelacion:EstadoDeApelacion,
+        descargo:String,//lo que escribio el usuario al apelar, vacio si no apelo.
+    }
+
+    impl Rechazo{
+        pub fn new(postulante:Postulante, motivo:MotivoDeRechazo)->Self{
+            Self{postulante,motivo,apelacion:EstadoDeApelacion::SinApelar,descargo:String::new()}
         }
+        pub fn get_postulante(&self)->Postulante{
+            self.postulante.clone()
+        }
+        pub fn get_rol(&self)->Rol{
+            self.postulante.get_rol()
+        }
+        pub fn get_motivo(&self)->MotivoDeRechazo{
+            self.motivo
+        }
+        pub fn get_apelacion(&self)->EstadoDeApelacion{
+            self.apelacion
+        }
+        pub fn get_descargo(&self)->String{
+            self.descargo.clone()
+        }
+    }
 
-        /// - obtiene todos los usuarios registrados en el sistema.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.obtener_usuarios_registrados();
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn obtener_usuarios_registrados(&self)-> Vec<Usuario>{
-            if Self::env().caller() ==self.admin.accountid{
-                self.usuarios_registrados.clone()
-            }else{
-                panic!("admin invalido")
-            }
+    /// Estado de la participacion de un usuario en una eleccion, lo devuelve mi_estado.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct EstadoDeParticipacion{
+        id_usuario:i16,
+        id_eleccion:i16,
+        cargo:String,
+        rol:Rol,
+        validacion:EstadoDeValidacion,
+        voto_emitido:bool,//siempre false para los candidatos.
+    }
+
+    impl EstadoDeParticipacion{
+        pub fn get_id_usuario(&self)->i16{
+            self.id_usuario
+        }
+        pub fn get_id_eleccion(&self)->i16{
+            self.id_eleccion
+        }
+        pub fn get_cargo(&self)->String{
+            self.cargo.clone()
+        }
+        pub fn get_rol(&self)->Rol{
+            self.rol
+        }
+        pub fn get_validacion(&self)->EstadoDeValidacion{
+            self.validacion
+        }
+        pub fn get_voto_emitido(&self)->bool{
+            self.voto_emitido
         }
+    }
 
-        /// - obtiene todas las elecciones en el sistema.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.obtener_todas_las_elecciones();
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn obtener_todas_las_elecciones(&self)-> Vec<Eleccion>{
-            if Self::env().caller() ==self.admin.accountid{
-                self.elecciones.clone()
-            }else{
-                panic!("admin invalido")
+    /// Cantidad maxima de elementos que devuelve una consulta paginada.
+    const MAX_TAMANIO_DE_PAGINA:u32 = 50;
+
+    /// Una pagina de una consulta, cada elemento va con su id.
+    /// siguiente es el valor de desde para pedir la proxima pagina, None si ya se recorrieron todos los datos.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo)
+    )]
+    pub struct Pagina<T>{
+        elementos:Vec<(i16, T)>,
+        siguiente:Option<u32>,
+    }
+
+    impl<T:Clone> Pagina<T>{
+        ///Arma la pagina recorriendo los datos desde la posicion desde y quedandose con los que cumplen el filtro.
+        fn armar(datos:&[T], desde:u32, cantidad:u32, filtro:impl Fn(&T)->bool)->Result<Self, String>{
+            Self::armar_con(datos, desde, cantidad, |dato| if filtro(dato) {Some(dato.clone())} else {None})
+        }
+        ///Igual que armar, pero convierte cada dato con la funcion, descartando los que devuelven None.
+        fn armar_con<D>(datos:&[D], desde:u32, cantidad:u32, convertir:impl Fn(&D)->Option<T>)->Result<Self, String>{
+            if cantidad==0 || cantidad>MAX_TAMANIO_DE_PAGINA{
+                return Err(String::from("La cantidad por pagina debe estar entre 1 y 50"));
+            }
+            let mut elementos = Vec::new();
+            let mut siguiente = None;
+            for (posicion, dato) in datos.iter().enumerate().skip(desde as usize){
+                if elementos.len() == cantidad as usize{
+                    siguiente = Some(posicion as u32);
+                    break;
+                }
+                if let Some(elemento) = convertir(dato){
+                    elementos.push(((posicion as i16).checked_add(1).unwrap(), elemento));
+                }
             }
+            Ok(Self{elementos,siguiente})
         }
-
-        ///devuelve true si el año es biciesto.
-        fn es_bisiesto(anio: i32) -> bool {
-            (anio % 4 == 0 && anio % 100 != 0) || (anio % 400 == 0)
+        pub fn get_elementos(&self)->Vec<(i16, T)>{
+            self.elementos.clone()
         }
-        
-        /// Devuelve la cantidad de dias del mes pasado por parametro.
-        fn dias_en_mes(anio: i32, mes: i32) -> i32 {
+        pub fn get_siguiente(&self)->Option<u32>{
+            self.siguiente
+        }
+    }
+
+    /// Cantidad maxima de opciones de un referendum.
+    const MAX_OPCIONES:usize = 16;
+    /// Largo maximo en bytes del texto de una opcion.
+    const MAX_LARGO_OPCION:usize = 128;
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Opcion{
+        texto:String,
+        cant_votos:i16,
+    }
+    impl Opcion{
+        pub fn new(texto:String)->Self{
+            Self{texto,cant_votos:0}
+        }
+        pub fn get_texto(&self)->String{
+            self.texto.clone()
+        }
+        pub fn get_cantidad_votos(&self)->i16{
+            self.cant_votos
+        }
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Lista{
+        nombre:String,
+        candidatos:Vec<i16>,//numeros de boleta de los candidatos en el orden de la lista, el primero es el lider.
+        cant_votos:i16,
+    }
+    impl Lista{
+        pub fn new(nombre:String, candidatos:Vec<i16>)->Self{
+            Self{nombre,candidatos,cant_votos:0}
+        }
+        pub fn get_nombre(&self)->String{
+            self.nombre.clone()
+        }
+        pub fn get_candidatos(&self)->Vec<i16>{
+            self.candidatos.clone()
+        }
+        /// Devuelve el numero de boleta del lider de la lista.
+        pub fn get_lider(&self)->Option<i16>{
+            self.candidatos.first().copied()
+        }
+        pub fn get_cantidad_votos(&self)->i16{
+            self.cant_votos
+        }
+    }
+
+    /// Largo maximo en bytes del nombre del partido.
+    const MAX_LARGO_PARTIDO:usize = 64;
+    /// Largo maximo en bytes de la plataforma del candidato.
+    const MAX_LARGO_PLATAFORMA:usize = 1024;
+    /// Largo maximo en bytes del hash o CID del contenido (foto y manifiesto).
+    const MAX_LARGO_HASH_CONTENIDO:usize = 128;
+
+    #[derive(scale::Decode, scale::Encode,Debug,Default,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PerfilCandidato{
+        partido:String,//partido o lista por el que se presenta.
+        plataforma:String,//texto corto con las propuestas.
+        hash_contenido:Option<String>,//hash o CID de IPFS con la foto y el manifiesto.
+    }
+
+    impl PerfilCandidato{
+        pub fn new(partido:String, plataforma:String, hash_contenido:Option<String>)->Self{
+            Self{partido,plataforma,hash_contenido}
+        }
+        pub fn get_partido(&self)->String{
+            self.partido.clone()
+        }
+        pub fn get_plataforma(&self)->String{
+            self.plataforma.clone()
+        }
+        pub fn get_hash_contenido(&self)->Option<String>{
+            self.hash_contenido.clone()
+        }
+        ///Controla que los textos del perfil no superen los largos maximos.
+        fn validar(&self)->Result<(),String>{
+            if self.partido.len()>MAX_LARGO_PARTIDO{
+                return Err(String::from("El nombre del partido es demasiado largo"));
+            }
+            if self.plataforma.len()>MAX_LARGO_PLATAFORMA{
+                return Err(String::from("La plataforma es demasiado larga"));
+            }
+            if let Some(hash) = &self.hash_contenido{
+                if hash.len()>MAX_LARGO_HASH_CONTENIDO{
+                    return Err(String::from("El hash del contenido es demasiado largo"));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Vista publica de un candidato para las aplicaciones de los votantes.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct FichaDeCandidato{
+        nombre:String,
+        apellido:String,
+        numero_de_boleta:i16,
+        perfil:PerfilCandidato,
+        id_cargo:i16,
+    }
+
+    impl FichaDeCandidato{
+        pub fn get_nombre(&self)->String{
+            self.nombre.clone()
+        }
+        pub fn get_apellido(&self)->String{
+            self.apellido.clone()
+        }
+        pub fn get_numero_de_boleta(&self)->i16{
+            self.numero_de_boleta
+        }
+        pub fn get_perfil(&self)->PerfilCandidato{
+            self.perfil.clone()
+        }
+        pub fn get_id_cargo(&self)->i16{
+            self.id_cargo
+        }
+    }
+    /// Version actual del formato de almacenamiento, cambia cada vez que cambia como se codifican los datos guardados.
+    /// - 1: formato original, sin cargos multiples ni numeros de boleta. Se lee con los tipos de version_1.
+    /// - 2: numeros de boleta, perfiles, listas, cargos multiples, referendums, rechazos, reglas de validacion, pausas,
+    ///   depositos, votos en blanco, resultado final, auditorias, comprobantes de voto y reportes rechazados.
+    ///   La raiz guarda la version y el avance de la migracion.
+    ///
+    /// Solo se incrementa con cada formato publicado, no con cada cambio mientras se desarrolla.
+    /// ink decodifica la raiz antes de ejecutar cualquier mensaje, asi que este codigo no puede leer datos de una version
+    /// anterior: primero hay que convertirlos con un contrato de migracion que conozca el formato viejo (ver migracion_v1).
+    pub const VERSION_DE_ALMACENAMIENTO:u16 = 2;
+
+    /// Avance de una migracion de almacenamiento que se hace por lotes.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Migracion{
+        proximo_usuario:u32,
+        proxima_eleccion:u32,
+    }
+
+    /// Tipos con el formato de almacenamiento de la version 1, para leer los datos que guardo ese codigo.
+    /// No se deben modificar, se tienen que codificar igual que los datos que ya estan guardados.
+    pub mod version_1 {
+        use ink::prelude::string::String;
+        use ink::prelude::vec::Vec;
+        use ink::primitives::AccountId;
+
+        #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+        #[cfg_attr(
+            feature = "std",
+            derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+        )]
+        pub struct PersonaV1{
+            pub nombre:String,
+            pub apellido:String,
+            pub dni:String,
+            pub accountid:AccountId,
+        }
+
+        #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+        #[cfg_attr(
+            feature = "std",
+            derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+        )]
+        pub struct UsuarioV1{
+            pub datos:PersonaV1,
+            pub participacion:Vec<bool>,
+        }
+
+        #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+        #[cfg_attr(
+            feature = "std",
+            derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+        )]
+        pub struct VotanteV1{
+            pub dato:PersonaV1,
+            pub estado_del_voto:bool,
+        }
+
+        #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+        #[cfg_attr(
+            feature = "std",
+            derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+        )]
+        pub struct CandidatoV1{
+            pub dato:PersonaV1,
+            pub cant_votos:i16,
+        }
+
+        #[derive(scale::Decode, scale::Encode,Debug,Default,Clone)]
+        #[cfg_attr(
+            feature = "std",
+            derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+        )]
+        pub struct EleccionV1{
+            pub cargo:String,
+            pub inicio:i64,
+            pub fin:i64,
+            pub postulados_a_votantes:Vec<VotanteV1>,
+            pub votantes:Vec<VotanteV1>,
+            pub postulados_a_candidatos:Vec<CandidatoV1>,
+            pub candidatos:Vec<CandidatoV1>,
+        }
+
+        /// Raiz del contrato en la version 1.
+        #[derive(scale::Decode, scale::Encode,Debug,Clone)]
+        #[cfg_attr(
+            feature = "std",
+            derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+        )]
+        pub struct SistemaDeVotacionV1{
+            pub admin:PersonaV1,
+            pub reporte_sin_permiso:Vec<AccountId>,
+            pub reportes_con_permiso:Vec<AccountId>,
+            pub usuarios_registrados:Vec<UsuarioV1>,
+            pub elecciones:Vec<EleccionV1>,
+        }
+
+        impl PersonaV1 {
+            pub(super) fn migrar(self)->super::Persona{
+                super::Persona{nombre:self.nombre, apellido:self.apellido, dni:self.dni, accountid:self.accountid}
+            }
+        }
+        impl VotanteV1 {
+            pub(super) fn migrar(self)->super::Votante{
+                super::Votante{dato:self.dato.migrar(), estado_del_voto:self.estado_del_voto}
+            }
+        }
+        impl CandidatoV1 {
+            /// El numero de boleta y el cargo quedan en 0, los asigna migrar_almacenamiento.
+            pub(super) fn migrar(self)->super::Candidato{
+                super::Candidato{dato:self.dato.migrar(), cant_votos:self.cant_votos, numero_de_boleta:0, perfil:super::PerfilCandidato::default(), id_cargo:0}
+            }
+        }
+        impl EleccionV1 {
+            pub(super) fn migrar(self, clave:u32)->super::Eleccion{
+                let mut eleccion = super::Eleccion::new(self.cargo, &self.inicio, &self.fin);
+                eleccion.clave = clave;
+                eleccion.postulados_a_votantes = self.postulados_a_votantes.into_iter().map(VotanteV1::migrar).collect();
+                eleccion.votantes = self.votantes.into_iter().map(VotanteV1::migrar).collect();
+                eleccion.postulados_a_candidatos = self.postulados_a_candidatos.into_iter().map(CandidatoV1::migrar).collect();
+                eleccion.candidatos = self.candidatos.into_iter().map(CandidatoV1::migrar).collect();
+                eleccion
+            }
+        }
+    }
+
+    /// Datos de una pausa de emergencia, del sistema completo o de una sola eleccion.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Pausa{
+        por:AccountId,
+        motivo:String,
+        desde:u64,//timestamp del bloque en el que se pauso.
+    }
+
+    impl Pausa{
+        pub fn get_por(&self)->AccountId{
+            self.por
+        }
+        pub fn get_motivo(&self)->String{
+            self.motivo.clone()
+        }
+        pub fn get_desde(&self)->u64{
+            self.desde
+        }
+    }
+
+    pub const MAX_LARGO_MOTIVO_DE_PAUSA:usize = 256;
+
+    pub const MAX_AUDITORES:usize = 32;
+    pub const MAX_LARGO_MOTIVO_DE_DISPUTA:usize = 512;
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoDeCertificacion{
+        SinFinalizar,
+        Pendiente,
+        Certificada,
+        Disputada,//algun auditor disputo el resultado y todavia no se llego al umbral.
+    }
+
+    /// En que quedo el pedido de acceso de un contrato de reporte.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoDeReporte{
+        SinPedido,
+        Pendiente,
+        Aprobado,
+        Rechazado,
+    }
+
+    /// Objecion de un auditor al resultado final.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Disputa{
+        auditor:AccountId,
+        motivo:String,
+    }
+
+    impl Disputa{
+        pub fn get_auditor(&self)->AccountId{
+            self.auditor
+        }
+        pub fn get_motivo(&self)->String{
+            self.motivo.clone()
+        }
+    }
+
+    /// Resultado de una eleccion congelado al finalizarla.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ResultadoFinal{
+        cargos:Vec<String>,
+        candidatos:Vec<Candidato>,
+        listas:Vec<Lista>,
+        opciones:Vec<Opcion>,
+        cantidad_de_votantes:i16,
+        votos_emitidos:i16,//incluye los votos en blanco.
+        votos_en_blanco:i16,
+        ganadores:Vec<Option<i16>>,//numero de boleta del ganador de cada cargo, o la opcion ganadora en un referendum. None si hay empate.
+        finalizada_en:u64,
+        cantidad_de_comprobantes:u32,
+        acumulador_de_comprobantes:Hash,//compromete el resultado con todos los comprobantes de voto.
+    }
+
+    impl ResultadoFinal{
+        pub fn get_cargos(&self)->Vec<String>{
+            self.cargos.clone()
+        }
+        pub fn get_candidatos(&self)->Vec<Candidato>{
+            self.candidatos.clone()
+        }
+        pub fn get_listas(&self)->Vec<Lista>{
+            self.listas.clone()
+        }
+        pub fn get_opciones(&self)->Vec<Opcion>{
+            self.opciones.clone()
+        }
+        pub fn get_cantidad_de_votantes(&self)->i16{
+            self.cantidad_de_votantes
+        }
+        pub fn get_votos_emitidos(&self)->i16{
+            self.votos_emitidos
+        }
+        pub fn get_votos_en_blanco(&self)->i16{
+            self.votos_en_blanco
+        }
+        pub fn get_ganadores(&self)->Vec<Option<i16>>{
+            self.ganadores.clone()
+        }
+        pub fn get_finalizada_en(&self)->u64{
+            self.finalizada_en
+        }
+        pub fn get_cantidad_de_comprobantes(&self)->u32{
+            self.cantidad_de_comprobantes
+        }
+        pub fn get_acumulador_de_comprobantes(&self)->Hash{
+            self.acumulador_de_comprobantes
+        }
+    }
+
+    /// Prueba de que un comprobante esta en la cadena de comprobantes de la eleccion.
+    /// acumulador es el hash de (acumulador_previo, comprobante). El sistema ya siguio la cadena con los comprobantes
+    /// posteriores, que tambien estan en los eventos VotoEmitido, y llego al acumulador_final.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo)
+    )]
+    pub struct InclusionDeComprobante{
+        posicion:u32,//orden del comprobante en la eleccion, desde 1.
+        acumulador_previo:Hash,
+        acumulador:Hash,
+        cantidad_de_comprobantes:u32,//la del resultado final si la eleccion esta finalizada.
+        acumulador_final:Hash,//el del resultado final si la eleccion esta finalizada.
+    }
+
+    impl InclusionDeComprobante{
+        pub fn get_posicion(&self)->u32{
+            self.posicion
+        }
+        pub fn get_acumulador_previo(&self)->Hash{
+            self.acumulador_previo
+        }
+        pub fn get_acumulador(&self)->Hash{
+            self.acumulador
+        }
+        pub fn get_cantidad_de_comprobantes(&self)->u32{
+            self.cantidad_de_comprobantes
+        }
+        pub fn get_acumulador_final(&self)->Hash{
+            self.acumulador_final
+        }
+    }
+
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,Copy,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoDeDeposito{
+        Retenido,
+        Reintegrado,
+        Perdido,//se transfirio a la tesoreria.
+    }
+
+    /// Deposito que pago un candidato al postularse.
+    #[derive(scale::Decode, scale::Encode,Debug,Clone,PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Deposito{
+        dato:Persona,
+        monto:Balance,
+        estado:EstadoDeDeposito,
+    }
+
+    impl Deposito{
+        pub fn get_cuenta(&self)->AccountId{
+            self.dato.accountid
+        }
+        pub fn get_dni(&self)->String{
+            self.dato.dni.clone()
+        }
+        pub fn get_monto(&self)->Balance{
+            self.monto
+        }
+        pub fn get_estado(&self)->EstadoDeDeposito{
+            self.estado
+        }
+    }
+
+    /// Se emite cuando el administrador pausa todo el sistema.
+    #[ink(event)]
+    pub struct SistemaPausado{
+        #[ink(topic)]
+        pub por:AccountId,
+        pub motivo:String,
+    }
+
+    /// Se emite cuando el administrador reanuda el sistema, extension es lo que duro la pausa si se pidio extender el fin.
+    /// Cada eleccion se corre solo lo que estuvo abierta durante la pausa.
+    #[ink(event)]
+    pub struct SistemaReanudado{
+        #[ink(topic)]
+        pub por:AccountId,
+        pub extension:u64,
+    }
+
+    /// Se emite cuando el administrador pausa una eleccion.
+    #[ink(event)]
+    pub struct EleccionPausada{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+        #[ink(topic)]
+        pub por:AccountId,
+        pub motivo:String,
+    }
+
+    /// Se emite cuando el administrador reanuda una eleccion, extension es lo que se corrio su fin.
+    #[ink(event)]
+    pub struct EleccionReanudada{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+        #[ink(topic)]
+        pub por:AccountId,
+        pub extension:u64,
+    }
+
+    /// Se emite cuando se registra un usuario, id_usuario es su posicion en el sistema.
+    #[ink(event)]
+    pub struct UsuarioRegistrado{
+        #[ink(topic)]
+        pub id_usuario:i16,
+        #[ink(topic)]
+        pub cuenta:AccountId,
+    }
+
+    /// Se emite cuando se crea una eleccion o un referendum, en un referendum el cargo es la pregunta.
+    #[ink(event)]
+    pub struct EleccionCreada{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+        pub cargo:String,
+        pub inicio:i64,
+        pub fin:i64,
+    }
+
+    /// Se emite cuando el administrador elimina una eleccion, las elecciones siguientes bajan un id.
+    #[ink(event)]
+    pub struct EleccionEliminada{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+    }
+
+    /// Se emite cuando un usuario se postula, id_cargo solo tiene sentido si no es votante.
+    #[ink(event)]
+    pub struct UsuarioPostulado{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+        #[ink(topic)]
+        pub id_usuario:i16,
+        pub es_votante:bool,
+        pub id_cargo:i16,
+    }
+
+    /// Se emite cuando una postulacion se aprueba o se rechaza, incluidas las apelaciones aceptadas.
+    #[ink(event)]
+    pub struct PostulacionResuelta{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+        #[ink(topic)]
+        pub id_usuario:i16,
+        pub aprobada:bool,
+    }
+
+    /// Se emite por cada voto registrado, no dice quien voto ni a quien.
+    #[ink(event)]
+    pub struct VotoEmitido{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+        pub comprobante:Hash,
+    }
+
+    /// Se emite cuando se finaliza una eleccion con el hash de su resultado final.
+    #[ink(event)]
+    pub struct EleccionFinalizada{
+        #[ink(topic)]
+        pub id_eleccion:i16,
+        pub hash_del_resultado:Hash,
+    }
+
+    /// Se emite cuando un reporte pide permiso para acceder al sistema.
+    #[ink(event)]
+    pub struct ReporteSolicitado{
+        #[ink(topic)]
+        pub cuenta:AccountId,
+    }
+
+    /// Se emite cuando el administrador resuelve el pedido de permiso de un reporte.
+    #[ink(event)]
+    pub struct ReporteResuelto{
+        #[ink(topic)]
+        pub cuenta:AccountId,
+        pub aprobado:bool,
+    }
+
+    #[ink(storage)]
+    pub struct SistemaDeVotacion{
+        admin:Persona,
+        reporte_sin_permiso:Vec<AccountId>,
+        reportes_con_permiso:Vec<AccountId>,
+        reportes_rechazados:Vec<AccountId>,
+        usuarios_registrados:Vec<Usuario>,//todos los usuarios regitrados en el sistema, pueden participar de una elecion o no
+        elecciones:Vec<Eleccion>,
+        version_de_almacenamiento:u16,//version del formato de los datos guardados, ver migrar_almacenamiento.
+        migracion:Option<Migracion>,//avance de la migracion en curso, None si no hay ninguna.
+        pausa:Option<Pausa>,//pausa de emergencia de todo el sistema.
+        tesoreria:AccountId,//cuenta que recibe los depositos perdidos, al crear el sistema es el admin.
+        proxima_clave_de_eleccion:u32,
+        comprobantes:Mapping<(u32, Hash), u32>,//posicion de cada comprobante en su eleccion, por clave de eleccion.
+        acumuladores:Mapping<(u32, u32), Hash>,//acumulador de la eleccion despues de cada posicion.
+        comprobantes_por_posicion:Mapping<(u32, u32), Hash>,//comprobante de cada posicion, para recalcular la cadena.
+    }
+    impl SistemaDeVotacion {
+        /// - Instancia el sistema de votacion.
+        ///  - lo setea con valores iniciales y asigna el accountid del administrador.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// ```
+        #[ink(constructor)]
+        #[allow(clippy::new_without_default)]
+        pub fn new() -> Self {
+            Self { 
+                admin: Persona::new(String::from("admin"), String::from("admin"), String::from("admin"),Self::env().caller() ),
+                usuarios_registrados:Vec::new(),
+                elecciones:Vec::new(),
+                reporte_sin_permiso:Vec::new(),
+                reportes_con_permiso:Vec::new(),
+                reportes_rechazados:Vec::new(),
+                version_de_almacenamiento:VERSION_DE_ALMACENAMIENTO,
+                migracion:None,
+                pausa:None,
+                tesoreria:Self::env().caller(),
+                proxima_clave_de_eleccion:1,
+                comprobantes:Mapping::default(),
+                acumuladores:Mapping::default(),
+                comprobantes_por_posicion:Mapping::default(),
+            }
+        }
+    
+        //METODOS ADMINISTRADOR
+
+        /// - Crea una eleccion y la agrega al sistema.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.crear_eleccion(String::from("un cargo"),1,1,2024,2,3,2024);
+        /// ```
+        /// 
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]//recibe las fechas como dia, mes y anio.
+        pub fn crear_eleccion(&mut self, cargo: String, dia_inicio: i32, mes_inicio: i32, anio_inicio: i32, dia_fin: i32, mes_fin: i32, anio_fin: i32) -> Result<(), String> {
+            let (fecha_de_inicio, fecha_de_fin) = Self::convertir_fechas(dia_inicio, mes_inicio, anio_inicio, dia_fin, mes_fin, anio_fin)?;
+            if Self::env().caller()  != self.admin.accountid {
+                return Err(String::from("No tienes permiso para crear una elección"));
+            }
+        
+            let elec = Eleccion::new(cargo, &fecha_de_inicio, &fecha_de_fin);
+            self.agregar_eleccion(elec);
+            Ok(())
+        }
+
+        /// - Crea un referendum, una eleccion sin candidatos donde se vota una de las opciones de la pregunta.
+        /// - Los votantes se postulan y se validan igual que en una eleccion comun.
+        /// - La regla de mayoria se aplica en el reporte para decidir la opcion ganadora.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::{SistemaDeVotacion, ReglaDeMayoria};
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let opciones = vec![String::from("Sí"),String::from("No")];
+        /// let r = sistema.crear_referendum(String::from("¿Aprueba la reforma del estatuto?"),opciones,ReglaDeMayoria::Absoluta,1,1,2024,2,3,2024);
+        /// ```
+        ///
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]//recibe las fechas como dia, mes y anio.
+        pub fn crear_referendum(&mut self, pregunta: String, opciones: Vec<String>, regla: ReglaDeMayoria, dia_inicio: i32, mes_inicio: i32, anio_inicio: i32, dia_fin: i32, mes_fin: i32, anio_fin: i32) -> Result<(), String> {
+            let (fecha_de_inicio, fecha_de_fin) = Self::convertir_fechas(dia_inicio, mes_inicio, anio_inicio, dia_fin, mes_fin, anio_fin)?;
+            if Self::env().caller()  != self.admin.accountid {
+                return Err(String::from("No tienes permiso para crear una elección"));
+            }
+            if opciones.len()<2 || opciones.len()>MAX_OPCIONES{
+                return Err(String::from("El referendum debe tener entre 2 y 16 opciones"));
+            }
+            for (i, opcion) in opciones.iter().enumerate(){
+                if opcion.is_empty() || opcion.len()>MAX_LARGO_OPCION{
+                    return Err(String::from("Texto de opcion invalido"));
+                }
+                if opciones[..i].contains(opcion){
+                    return Err(String::from("Las opciones no se pueden repetir"));
+                }
+            }
+            if let ReglaDeMayoria::Calificada(puntos_basicos) = regla{
+                if puntos_basicos==0 || puntos_basicos>10_000{
+                    return Err(String::from("La mayoria calificada debe estar entre 1 y 10000 puntos basicos"));
+                }
+            }
+
+            let mut elec = Eleccion::new(pregunta, &fecha_de_inicio, &fecha_de_fin);
+            elec.tipo = TipoDeEleccion::Referendum;
+            elec.opciones = opciones.into_iter().map(Opcion::new).collect();
+            elec.regla_de_mayoria = regla;
+            self.agregar_eleccion(elec);
+            Ok(())
+        }
+
+        ///Valida las fechas de una eleccion y las devuelve convertidas a timestamp.
+        fn convertir_fechas(dia_inicio: i32, mes_inicio: i32, anio_inicio: i32, dia_fin: i32, mes_fin: i32, anio_fin: i32) -> Result<(i64, i64), String> {
+            if Self::dias_en_mes(anio_inicio,mes_inicio)==0||Self::dias_en_mes(anio_inicio,mes_inicio)<dia_inicio||dia_inicio<0 {
+                return Err(String::from("Fecha de inicio invalida"));
+            }
+
+            if (Self::dias_en_mes(anio_fin,mes_fin)==0)||Self::dias_en_mes(anio_fin,mes_fin)<dia_fin||dia_fin<0{
+                return Err(String::from("Fecha de fin invalida"));
+            }
+
+            let fecha_de_inicio = Self::timestamp(anio_inicio, mes_inicio, dia_inicio, 0, 0, 0,0);
+            let fecha_de_inicio = match fecha_de_inicio {
+                Ok(dato) => dato,
+                Err(_e) => return Err(String::from("No se puede convertir fecha de inicio ")),
+            };
+            let fecha_de_fin = Self::timestamp(anio_fin, mes_fin, dia_fin, 0, 0, 0,0);
+            let fecha_de_fin = match fecha_de_fin {
+                Ok(dato) => dato,
+                Err(_e) => return Err(String::from("No se puede convertir fecha de fin ")),
+                };
+        
+            if fecha_de_inicio >= fecha_de_fin {
+                return Err(String::from("La fecha de inicio debe ser anterior a la fecha de fin"));
+            }
+            Ok((fecha_de_inicio, fecha_de_fin))
+        }
+
+        ///Agrega la eleccion al sistema y la suma a la participacion de cada usuario.
+        fn agregar_eleccion(&mut self, mut elec:Eleccion){
+            elec.clave = self.proxima_clave_de_eleccion;
+            self.proxima_clave_de_eleccion = self.proxima_clave_de_eleccion.checked_add(1).unwrap();
+            let evento = EleccionCreada{id_eleccion:(self.elecciones.len() as i16).checked_add(1).unwrap(), cargo:elec.cargo.clone(), inicio:elec.inicio, fin:elec.fin};
+            self.elecciones.push(elec);
+            self.env().emit_event(evento);
+            for e in self.usuarios_registrados.iter_mut() {
+                e.participacion.push(false);
+            }
+        }
+
+        /// - Elimina una eleccion del sistema, las siguientes pasan a tener un id menos.
+        /// - No se puede eliminar una eleccion finalizada ni una anterior a una finalizada: los reportes guardan
+        ///   los resultados finales por id y no deben pasar a ser de otra eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.eliminar_eleccion(1);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn eliminar_eleccion(&mut self, id_eleccion:i32) -> Result<(), String> {
+
+            if id_eleccion as usize>self.elecciones.len()||id_eleccion<1{
+                return Err(String::from("Id de eleccion invalido"));
+            }
+
+            if Self::env().caller()  != self.admin.accountid {
+                return Err(String::from("No tienes permiso para crear una elección"));
+            }
+
+            if self.elecciones[(id_eleccion.checked_sub(1).unwrap())as usize].resultado_final.is_some(){
+                return Err(String::from("La eleccion esta finalizada"));
+            }
+
+            if self.elecciones[(id_eleccion as usize)..].iter().any(|e| e.resultado_final.is_some()){
+                return Err(String::from("Hay una eleccion finalizada despues de esta, eliminarla cambiaria su id"));
+            }
+
+            if self.elecciones[(id_eleccion.checked_sub(1).unwrap())as usize].depositos.iter().any(|d| d.estado == EstadoDeDeposito::Retenido){
+                return Err(String::from("La eleccion tiene depositos sin liquidar"));
+            }
+        
+            self.elecciones.remove((id_eleccion.checked_sub(1).unwrap())as usize);
+            for e in self.usuarios_registrados.iter_mut() {
+                e.participacion.remove((id_eleccion.checked_sub(1).unwrap())as usize);
+            }
+            self.env().emit_event(EleccionEliminada{id_eleccion:id_eleccion as i16});
+            Ok(())
+        }
+
+        ///Retorna true si el id de la eleccion es valida.
+        fn existe_eleccion(&self,id:i16)->bool{
+            if id==0{
+                false
+            }else{
+                self.elecciones.len()>=(id as usize)
+            }
+        }
+
+        /// Retorna true si el id de usuario es valido.
+        fn existe_usuario(&self,id:i16)->bool{
+            if id==0{
+                false
+            }else{
+                self.usuarios_registrados.len()>=id as usize
+            }
+        }
+
+        /// Retorna true si la eleccion no ha empezado.
+        fn eleccion_no_empezada(&self,id:i16)->bool{
+            if id!=0 || self.elecciones.len()>=id as usize{
+                let eleccion=self.elecciones.get(id.checked_sub(1).unwrap() as usize).unwrap();
+                if Self::env().block_timestamp() < eleccion.inicio as u64{
+                    return true;
+                }
+            };
+            false
+        }
+
+        /// - Recibe un AccountId y lo asigna como administrador.
+        /// - Funciona solo si el es el administrador quien lo llama, de caso contrario da error.
+        /// - EJEMPLO
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.ceder_admin(accounts.charlie);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn ceder_admin(&mut self, actid: AccountId) -> Result<(), String> {
+            if Self::env().caller() == self.admin.accountid {
+                self.admin.accountid=actid;
+                Ok(())
+            } else {
+                Err(String::from("No tiene permiso de admin para ejecutar este método."))
+            }
+        }
+
+        /// - Reemplaza el codigo del contrato manteniendo su direccion y todos sus datos.
+        /// - Solo lo puede hacer el administrador. Si el nuevo codigo cambia el formato de los datos, code_hash tiene que ser
+        ///   el de un contrato de migracion que los convierta (ver VERSION_DE_ALMACENAMIENTO) y despues se debe llamar
+        ///   a migrar_almacenamiento hasta que devuelva true.
+        /// - EJEMPLO:
+        /// ```no_run
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.actualizar_codigo([0x01; 32].into());
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn actualizar_codigo(&mut self, code_hash:Hash)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            match self.env().set_code_hash(&code_hash){
+                Ok(()) => Ok(()),
+                Err(_) => Err(String::from("No se pudo actualizar el codigo del contrato")),
+            }
+        }
+
+        /// - Migra los datos guardados al formato de la version actual, procesando como maximo lote usuarios y elecciones.
+        /// - Devuelve true cuando la migracion termino, si devuelve false hay que volver a llamarlo.
+        /// - Solo lo puede hacer el administrador.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.migrar_almacenamiento(50);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn migrar_almacenamiento(&mut self, lote:u32)->Result<bool, String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if self.version_de_almacenamiento == VERSION_DE_ALMACENAMIENTO{
+                return Ok(true);
+            }
+            if lote==0 || lote>MAX_TAMANIO_DE_LOTE{
+                return Err(String::from("El lote debe estar entre 1 y 100"));
+            }
+            let mut migracion = self.migracion.take().unwrap_or(Migracion{proximo_usuario:0,proxima_eleccion:0});
+            let cantidad_de_elecciones = self.elecciones.len();
+            let mut restante = lote;
+            while restante>0 && (migracion.proximo_usuario as usize) < self.usuarios_registrados.len(){
+                let usuario = &mut self.usuarios_registrados[migracion.proximo_usuario as usize];
+                usuario.participacion.resize(cantidad_de_elecciones, false);
+                migracion.proximo_usuario = migracion.proximo_usuario.checked_add(1).unwrap();
+                restante = restante.checked_sub(1).unwrap();
+            }
+            while restante>0 && (migracion.proxima_eleccion as usize) < cantidad_de_elecciones{
+                self.elecciones[migracion.proxima_eleccion as usize].migrar();
+                migracion.proxima_eleccion = migracion.proxima_eleccion.checked_add(1).unwrap();
+                restante = restante.checked_sub(1).unwrap();
+            }
+            if (migracion.proximo_usuario as usize) < self.usuarios_registrados.len() || (migracion.proxima_eleccion as usize) < cantidad_de_elecciones{
+                self.migracion = Some(migracion);
+                return Ok(false);
+            }
+            self.version_de_almacenamiento = VERSION_DE_ALMACENAMIENTO;
+            Ok(true)
+        }
+
+        /// - Devuelve la version del formato de los datos guardados y si hay una migracion pendiente.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let (version, pendiente) = sistema.version_de_almacenamiento();
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn version_de_almacenamiento(&self)->(u16, bool){
+            (self.version_de_almacenamiento, self.version_de_almacenamiento != VERSION_DE_ALMACENAMIENTO)
+        }
+
+        /// - Lee la raiz guardada con el formato de la version 1 y la reemplaza por la del formato actual.
+        /// - No es un mensaje porque este codigo no puede decodificar esa raiz, lo llama el contrato migracion_v1
+        ///   que tiene como raiz los tipos de version_1. Solo lo puede hacer el administrador guardado en esos datos.
+        /// - Los datos quedan en la version 1 hasta que migrar_almacenamiento complete los cargos, los numeros de boleta
+        ///   y la participacion de los usuarios.
+        pub fn migrar_desde_version_1()->Result<(), String>{
+            let raiz = <Self as ink::storage::traits::StorageKey>::KEY;
+            let anterior = match ink::env::get_contract_storage::<_, version_1::SistemaDeVotacionV1>(&raiz){
+                Ok(Some(anterior)) => anterior,
+                _ => return Err(String::from("No hay datos guardados con el formato de la version 1")),
+            };
+            if Self::env().caller() != anterior.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            let mut elecciones = Vec::new();
+            for eleccion in anterior.elecciones{
+                let clave = (elecciones.len() as u32).checked_add(1).unwrap();
+                elecciones.push(eleccion.migrar(clave));
+            }
+            let admin = anterior.admin.migrar();
+            let sistema = Self{
+                tesoreria:admin.accountid,
+                admin,
+                reporte_sin_permiso:anterior.reporte_sin_permiso,
+                reportes_con_permiso:anterior.reportes_con_permiso,
+                reportes_rechazados:Vec::new(),
+                usuarios_registrados:anterior.usuarios_registrados.into_iter().map(|usuario| Usuario{datos:usuario.datos.migrar(), participacion:usuario.participacion}).collect(),
+                proxima_clave_de_eleccion:(elecciones.len() as u32).checked_add(1).unwrap(),
+                elecciones,
+                version_de_almacenamiento:1,
+                migracion:None,
+                pausa:None,
+                comprobantes:Mapping::default(),
+                acumuladores:Mapping::default(),
+                comprobantes_por_posicion:Mapping::default(),
+            };
+            ink::env::set_contract_storage(&raiz, &sistema);
+            Ok(())
+        }
+
+        /// - Pausa todo el sistema: ninguna eleccion acepta postulaciones, apelaciones ni votos hasta que se reanude.
+        /// - Solo lo puede hacer el administrador, que puede seguir configurando las elecciones mientras tanto.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.pausar_sistema(String::from("Error en el conteo"));
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn pausar_sistema(&mut self, motivo:String)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if self.pausa.is_some(){
+                return Err(String::from("El sistema ya esta pausado"));
+            }
+            let pausa = self.nueva_pausa(motivo)?;
+            self.env().emit_event(SistemaPausado{por:pausa.por, motivo:pausa.motivo.clone()});
+            self.pausa = Some(pausa);
+            Ok(())
+        }
+
+        /// - Reanuda el sistema pausado.
+        /// - Si extender_fin es true, el fin de cada eleccion se corre lo que estuvo abierta durante la pausa. Si la eleccion
+        ///   tambien esta pausada por su cuenta, el tiempo desde su propia pausa se extiende cuando se reanude la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.reanudar_sistema(true);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn reanudar_sistema(&mut self, extender_fin:bool)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            let pausa = match self.pausa.take(){
+                Some(pausa) => pausa,
+                None => return Err(String::from("El sistema no esta pausado")),
+            };
+            let ahora = Self::env().block_timestamp();
+            let mut extension = 0;
+            if extender_fin{
+                extension = ahora.saturating_sub(pausa.desde);
+                for eleccion in self.elecciones.iter_mut(){
+                    let hasta = eleccion.pausa.as_ref().map_or(ahora, |propia| propia.desde.min(ahora));
+                    eleccion.extender_fin(pausa.desde, hasta);
+                }
+            }
+            self.env().emit_event(SistemaReanudado{por:Self::env().caller(), extension});
+            Ok(())
+        }
+
+        /// - Pausa una eleccion: no acepta postulaciones, apelaciones ni votos hasta que se reanude.
+        /// - Solo lo puede hacer el administrador.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.pausar_eleccion(1,String::from("Candidato duplicado"));
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn pausar_eleccion(&mut self, id_eleccion:i16, motivo:String)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize].resultado_final.is_some(){
+                return Err(String::from("La eleccion esta finalizada"));
+            }
+            if self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize].pausa.is_some(){
+                return Err(String::from("La eleccion ya esta pausada"));
+            }
+            let pausa = self.nueva_pausa(motivo)?;
+            self.env().emit_event(EleccionPausada{id_eleccion, por:pausa.por, motivo:pausa.motivo.clone()});
+            self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize].pausa = Some(pausa);
+            Ok(())
+        }
+
+        /// - Reanuda una eleccion pausada.
+        /// - Si extender_fin es true, su fin se corre lo que estuvo abierta durante la pausa. Si el sistema tambien esta
+        ///   pausado, el tiempo desde la pausa del sistema se extiende cuando se reanude el sistema.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.reanudar_eleccion(1,true);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn reanudar_eleccion(&mut self, id_eleccion:i16, extender_fin:bool)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            let ahora = Self::env().block_timestamp();
+            let hasta = self.pausa.as_ref().map_or(ahora, |del_sistema| del_sistema.desde.min(ahora));
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            let pausa = match eleccion.pausa.take(){
+                Some(pausa) => pausa,
+                None => return Err(String::from("La eleccion no esta pausada")),
+            };
+            let mut extension = 0;
+            if extender_fin{
+                extension = eleccion.extender_fin(pausa.desde, hasta);
+            }
+            self.env().emit_event(EleccionReanudada{id_eleccion, por:Self::env().caller(), extension});
+            Ok(())
+        }
+
+        /// - Configura el deposito que pagan los candidatos y el porcentaje minimo de votos (en puntos basicos) para recuperarlo.
+        /// - Solo lo puede hacer el administrador, antes de que empiece la eleccion y mientras nadie se haya postulado como candidato.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.configurar_deposito(1,1000,500);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn configurar_deposito(&mut self, id_eleccion:i16, monto:Balance, minimo_para_reintegro:u16)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("Eleccion ya empezada, no se puede cambiar el deposito"));
+            }
+            if minimo_para_reintegro>10000{
+                return Err(String::from("El minimo para el reintegro no puede superar 10000 puntos basicos"));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if !eleccion.postulados_a_candidatos.is_empty() || !eleccion.candidatos.is_empty() || !eleccion.depositos.is_empty(){
+                return Err(String::from("Ya hay candidatos postulados en la eleccion"));
+            }
+            eleccion.deposito = monto;
+            eleccion.minimo_para_reintegro = minimo_para_reintegro;
+            Ok(())
+        }
+
+        /// - Configura cuantos milisegundos abarca cada intervalo de la linea de tiempo de votos, por defecto es una hora.
+        /// - Solo lo puede hacer el administrador antes de que empiece la eleccion, el minimo es un minuto.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.configurar_intervalo_de_votos(1,1_800_000);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn configurar_intervalo_de_votos(&mut self, id_eleccion:i16, intervalo:u64)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("Eleccion ya empezada, no se puede cambiar el intervalo"));
+            }
+            if intervalo < MIN_INTERVALO_DE_VOTOS{
+                return Err(String::from("El intervalo no puede ser menor a un minuto"));
+            }
+            self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize].intervalo_de_votos = intervalo;
+            Ok(())
+        }
+
+        /// - Cambia la cuenta que recibe los depositos perdidos.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.configurar_tesoreria(accounts.charlie);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn configurar_tesoreria(&mut self, cuenta:AccountId)->Result<(), String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            self.tesoreria = cuenta;
+            Ok(())
+        }
+
+        /// - Devuelve el deposito al candidato si fue aprobado y obtuvo el minimo de votos configurado.
+        /// - Para el minimo se suman los votos directos al candidato y los votos a las listas que integra.
+        /// - Solo lo puede pedir el mismo usuario una vez terminada la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.retirar_deposito(1,1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn retirar_deposito(&mut self, id_usuario:i16, id_eleccion:i16)->Result<Balance, String>{
+            if !self.existe_usuario(id_usuario){
+                return Err(String::from("No existe usuario"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No Existe la eleccion "));
+            }
+            let dato = self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone();
+            if Self::env().caller() != dato.accountid{
+                return Err(String::from("No tiene permiso de usuario "));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if eleccion.get_estado(Self::env().block_timestamp()) != EstadoDeEleccion::Cerrada{
+                return Err(String::from("La eleccion todavia no termino"));
+            }
+            let reintegro = eleccion.corresponde_reintegro(&dato);
+            let deposito = match eleccion.depositos.iter_mut().find(|d| d.dato == dato){
+                Some(deposito) if deposito.estado == EstadoDeDeposito::Retenido => deposito,
+                Some(_) => return Err(String::from("El deposito ya fue liquidado")),
+                None => return Err(String::from("El usuario no tiene deposito en la eleccion")),
+            };
+            if !reintegro{
+                return Err(String::from("El candidato no alcanzo el minimo de votos para recuperar el deposito"));
+            }
+            deposito.estado = EstadoDeDeposito::Reintegrado;
+            let monto = deposito.monto;
+            if self.env().transfer(dato.accountid, monto).is_err(){
+                return Err(String::from("No se pudo transferir el deposito"));
+            }
+            Ok(monto)
+        }
+
+        /// - Transfiere a la tesoreria los depositos retenidos que no corresponde reintegrar y devuelve el total transferido.
+        /// - Solo lo puede hacer el administrador una vez terminada la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.liquidar_depositos(1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn liquidar_depositos(&mut self, id_eleccion:i16)->Result<Balance, String>{
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if eleccion.get_estado(Self::env().block_timestamp()) != EstadoDeEleccion::Cerrada{
+                return Err(String::from("La eleccion todavia no termino"));
+            }
+            let mut perdidos:Vec<usize> = Vec::new();
+            for (i, deposito) in eleccion.depositos.iter().enumerate(){
+                if deposito.estado == EstadoDeDeposito::Retenido && !eleccion.corresponde_reintegro(&deposito.dato){
+                    perdidos.push(i);
+                }
+            }
+            let mut total:Balance = 0;
+            for i in perdidos{
+                eleccion.depositos[i].estado = EstadoDeDeposito::Perdido;
+                total = total.checked_add(eleccion.depositos[i].monto).unwrap();
+            }
+            if total>0 && self.env().transfer(self.tesoreria, total).is_err(){
+                return Err(String::from("No se pudo transferir a la tesoreria"));
+            }
+            Ok(total)
+        }
+
+        /// - Devuelve la pausa del sistema, si esta pausado.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.pausa_del_sistema();
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn pausa_del_sistema(&self)->Option<Pausa>{
+            self.pausa.clone()
+        }
+
+        ///Arma la pausa con el llamador y el timestamp actual, controlando el largo del motivo.
+        fn nueva_pausa(&self, motivo:String)->Result<Pausa, String>{
+            if motivo.len()>MAX_LARGO_MOTIVO_DE_PAUSA{
+                return Err(String::from("El motivo es demasiado largo"));
+            }
+            Ok(Pausa{por:Self::env().caller(), motivo, desde:Self::env().block_timestamp()})
+        }
+
+        ///Controla que ni el sistema ni la eleccion esten pausados.
+        fn verificar_no_pausada(&self, id_eleccion:i16)->Result<(), String>{
+            if self.pausa.is_some(){
+                return Err(String::from("El sistema esta pausado"));
+            }
+            match self.elecciones.get(id_eleccion.checked_sub(1).unwrap() as usize){
+                Some(eleccion) if eleccion.pausa.is_some() => Err(String::from("La eleccion esta pausada")),
+                _ => Ok(()),
+            }
+        }
+
+        /// - Retorna true si se pudo validar con exito, false en caso contrario.
+        /// - Valida solo si el usuario esta postulado para esa eleccion.
+        /// - Si valido es false rechaza la postulacion sin especificar el motivo, ver rechazar_usuario.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.validar_usuario(1,2,true);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn validar_usuario(&mut self, id_usuario:i16, id_eleccion:i16, valido:bool)->Result<(),String>{
+            self.verificar_validacion(id_usuario, id_eleccion)?;
+            if valido {
+                self.aprobar(id_usuario, id_eleccion);
+                Ok(())
+            } else {
+                self.rechazar(id_usuario, id_eleccion, MotivoDeRechazo::SinEspecificar)
+            }
+        }
+
+        /// - Rechaza la postulacion pendiente de un usuario indicando el motivo.
+        /// - El usuario pasa a la lista de rechazados de la eleccion y puede apelar antes de que empiece.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::{SistemaDeVotacion, MotivoDeRechazo};
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.rechazar_usuario(1,2,MotivoDeRechazo::DatosIncompletos);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn rechazar_usuario(&mut self, id_usuario:i16, id_eleccion:i16, motivo:MotivoDeRechazo)->Result<(),String>{
+            self.verificar_validacion(id_usuario, id_eleccion)?;
+            self.rechazar(id_usuario, id_eleccion, motivo)
+        }
+
+        /// - Resuelve la apelacion pendiente de un usuario rechazado.
+        /// - Si se acepta, el usuario queda aprobado en su rol; si no, sigue rechazado.
+        /// - Solo lo puede hacer el administrador antes de que empiece la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.resolver_apelacion(1,2,true);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn resolver_apelacion(&mut self, id_usuario:i16, id_eleccion:i16, aceptada:bool)->Result<(),String>{
+            self.verificar_validacion(id_usuario, id_eleccion)?;
+            let dato = self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone();
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            let rechazo = eleccion.rechazados.iter_mut().find(|r| *r.postulante.get_dato() == dato);
+            let rechazo = match rechazo{
+                Some(rechazo) if rechazo.apelacion == EstadoDeApelacion::Pendiente => rechazo,
+                _ => return Err(String::from("El usuario no tiene una apelacion pendiente en la eleccion")),
+            };
+            if !aceptada{
+                rechazo.apelacion = EstadoDeApelacion::Denegada;
+                return Ok(());
+            }
+            rechazo.apelacion = EstadoDeApelacion::Aceptada;
+            match rechazo.postulante.clone(){
+                Postulante::Votante(votante) => eleccion.votantes.push(votante),
+                Postulante::Candidato(candidato) => eleccion.candidatos.push(candidato),
+            }
+            self.env().emit_event(PostulacionResuelta{id_eleccion, id_usuario, aprobada:true});
+            Ok(())
+        }
+
+        /// - Valida o rechaza en una sola llamada una lista de decisiones (id_usuario, valido) para la eleccion.
+        /// - Si atomico es true, primero controla todas las decisiones y si alguna falla no aplica ninguna.
+        /// - Si atomico es false, aplica las que se pueden y devuelve el resultado de cada una en el mismo orden.
+        /// - Se pueden enviar hasta 100 decisiones por llamada.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.validar_usuarios(1,vec![(1,true),(2,false)],true);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn validar_usuarios(&mut self, id_eleccion:i16, decisiones:Vec<(i16, bool)>, atomico:bool)->Result<Vec<Result<(),String>>,String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if decisiones.is_empty() || decisiones.len()>MAX_TAMANIO_DE_LOTE as usize{
+                return Err(String::from("Se deben enviar entre 1 y 100 decisiones"));
+            }
+            if atomico{
+                for (i, (id_usuario, _)) in decisiones.iter().enumerate(){
+                    let control = if decisiones[..i].iter().any(|(otro, _)| otro == id_usuario){
+                        Err(String::from("El usuario esta repetido en el lote"))
+                    }else{
+                        self.verificar_validacion(*id_usuario, id_eleccion).and_then(|_| self.verificar_pendiente(*id_usuario, id_eleccion))
+                    };
+                    if let Err(e) = control{
+                        let mut error_message = String::from("Decision ");
+                        error_message.push_str(&(i.checked_add(1).unwrap()).to_string());
+                        error_message.push_str(": ");
+                        error_message.push_str(&e);
+                        return Err(error_message);
+                    }
+                }
+            }
+            let mut resultados = Vec::new();
+            for (id_usuario, valido) in decisiones{
+                let resultado = self.verificar_validacion(id_usuario, id_eleccion)
+                    .and_then(|_| self.verificar_pendiente(id_usuario, id_eleccion))
+                    .and_then(|_| if valido {
+                        self.aprobar(id_usuario, id_eleccion);
+                        Ok(())
+                    } else {
+                        self.rechazar(id_usuario, id_eleccion, MotivoDeRechazo::SinEspecificar)
+                    });
+                resultados.push(resultado);
+            }
+            Ok(resultados)
+        }
+
+        /// - Configura la regla que usa validar_pendientes para aprobar postulaciones de la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::{SistemaDeVotacion, ReglaDeValidacion};
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.configurar_regla_de_validacion(1,ReglaDeValidacion::Padron(vec![String::from("7654456")]));
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn configurar_regla_de_validacion(&mut self, id_eleccion:i16, regla:ReglaDeValidacion)->Result<(),String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if let ReglaDeValidacion::Padron(padron) = &regla{
+                if padron.len()>MAX_TAMANIO_DE_PADRON{
+                    return Err(String::from("El padron es demasiado grande"));
+                }
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            eleccion.regla_de_validacion = Some(regla);
+            Ok(())
+        }
+
+        /// - Aprueba las postulaciones pendientes de la eleccion que cumplen la regla configurada.
+        /// - Aprueba como maximo la cantidad indicada (hasta 100) y devuelve cuantas aprobo, se puede llamar de nuevo para seguir.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.validar_pendientes(1,50);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn validar_pendientes(&mut self, id_eleccion:i16, maximo:u32)->Result<u32,String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("Eleccio ya empezada, no se puede validar luego de empezar"));
+            }
+            if maximo==0 || maximo>MAX_TAMANIO_DE_LOTE{
+                return Err(String::from("El maximo debe estar entre 1 y 100"));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            let regla = match eleccion.regla_de_validacion.clone(){
+                Some(regla) => regla,
+                None => return Err(String::from("La eleccion no tiene una regla de validacion configurada")),
+            };
+            let mut aprobados:u32 = 0;
+            let mut datos_aprobados = Vec::new();
+            let mut i = 0;
+            while i < eleccion.postulados_a_votantes.len() && aprobados < maximo{
+                if regla.cumple(&eleccion.postulados_a_votantes[i].dato, Rol::Votante){
+                    let votante = eleccion.postulados_a_votantes.remove(i);
+                    datos_aprobados.push(votante.dato.clone());
+                    eleccion.votantes.push(votante);
+                    aprobados = aprobados.checked_add(1).unwrap();
+                }else{
+                    i = i.checked_add(1).unwrap();
+                }
+            }
+            let mut i = 0;
+            while i < eleccion.postulados_a_candidatos.len() && aprobados < maximo{
+                if regla.cumple(&eleccion.postulados_a_candidatos[i].dato, Rol::Candidato){
+                    let candidato = eleccion.postulados_a_candidatos.remove(i);
+                    datos_aprobados.push(candidato.dato.clone());
+                    eleccion.candidatos.push(candidato);
+                    aprobados = aprobados.checked_add(1).unwrap();
+                }else{
+                    i = i.checked_add(1).unwrap();
+                }
+            }
+            for dato in datos_aprobados{
+                if let Some(posicion) = self.usuarios_registrados.iter().position(|u| u.datos == dato){
+                    self.env().emit_event(PostulacionResuelta{id_eleccion, id_usuario:(posicion as i16).checked_add(1).unwrap(), aprobada:true});
+                }
+            }
+            Ok(aprobados)
+        }
+
+        ///Controla que el usuario tenga una postulacion pendiente en la eleccion.
+        fn verificar_pendiente(&self, id_usuario:i16, id_eleccion:i16)->Result<(),String>{
+            let dato = &self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos;
+            let eleccion = &self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize];
+            if eleccion.postulados_a_votantes.iter().any(|x| x.dato == *dato) || eleccion.postulados_a_candidatos.iter().any(|x| x.dato == *dato){
+                Ok(())
+            }else{
+                Err(String::from("El usuario no tiene una postulacion pendiente en la eleccion"))
+            }
+        }
+
+        ///Controla que el administrador pueda validar o rechazar al usuario en la eleccion.
+        fn verificar_validacion(&self, id_usuario:i16, id_eleccion:i16)->Result<(),String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            
+            if !self.existe_usuario(id_usuario){
+                return Err(String::from("No existe usuario con ese id"));
+            }
+            
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("Eleccio ya empezada, no se puede validar luego de empezar"));
+            } 
+
+            if !self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].participacion[id_eleccion.checked_sub(1).unwrap() as usize]{
+                return Err(String::from("IEl usuario no esta participando en la eleccion con ese id"));
+            }
+            Ok(())
+        }
+
+        ///Pasa al usuario de postulado a aprobado, como votante o como candidato segun se postulo.
+        fn aprobar(&mut self, id_usuario:i16, id_eleccion:i16){
+            let vot = Votante::new(self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone());
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if let Some(position) = eleccion.postulados_a_votantes.iter().position(|x| *x == vot.clone()) {
+                eleccion.postulados_a_votantes.remove(position);
+                eleccion.votantes.push(vot);
+            } else {
+                let dato = self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone();
+                match eleccion.postulados_a_candidatos.iter().position(|x| x.dato == dato) {
+                    Some(position) => {
+                        let can = eleccion.postulados_a_candidatos.remove(position);
+                        eleccion.candidatos.push(can);
+                    }
+                    None => return,
+                }
+            }
+            self.env().emit_event(PostulacionResuelta{id_eleccion, id_usuario, aprobada:true});
+        }
+
+        ///Pasa al usuario de postulado a rechazado con el motivo indicado.
+        fn rechazar(&mut self, id_usuario:i16, id_eleccion:i16, motivo:MotivoDeRechazo)->Result<(),String>{
+            let dato = self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone();
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            let postulante = if let Some(position) = eleccion.postulados_a_votantes.iter().position(|x| x.dato == dato) {
+                Postulante::Votante(eleccion.postulados_a_votantes.remove(position))
+            } else if let Some(position) = eleccion.postulados_a_candidatos.iter().position(|x| x.dato == dato) {
+                Postulante::Candidato(eleccion.postulados_a_candidatos.remove(position))
+            } else {
+                return Err(String::from("El usuario no tiene una postulacion pendiente en la eleccion"));
+            };
+            eleccion.rechazados.push(Rechazo::new(postulante, motivo));
+            self.env().emit_event(PostulacionResuelta{id_eleccion, id_usuario, aprobada:false});
+            Ok(())
+        }
+
+        /// - Agrega un cargo mas a la eleccion, para elegir varios cargos en la misma boleta.
+        /// - Solo lo puede hacer el administrador antes de que empiece la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.agregar_cargo(1,String::from("Tesorero"));
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn agregar_cargo(&mut self, id_eleccion:i16, cargo:String)->Result<(), String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("Eleccion ya empezada, no se pueden agregar cargos"));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if eleccion.cargos.contains(&cargo){
+                return Err(String::from("El cargo ya existe en la eleccion"));
+            }
+            eleccion.cargos.push(cargo);
+            Ok(())
+        }
+
+        /// - Crea una lista cerrada en la eleccion con los numeros de boleta de candidatos ya aprobados.
+        /// - El orden de los candidatos es el de la lista y el primero es el lider.
+        /// - Cada candidato puede pertenecer a una sola lista y solo se puede hacer antes de que empiece la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.crear_lista(1,String::from("Lista Azul"),vec![2,1]);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn crear_lista(&mut self, id_eleccion:i16, nombre:String, candidatos:Vec<i16>)->Result<(), String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("Eleccion ya empezada, no se pueden crear listas"));
+            }
+            if candidatos.is_empty(){
+                return Err(String::from("La lista debe tener al menos un candidato"));
+            }
+
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if eleccion.listas.iter().any(|l| l.nombre == nombre){
+                return Err(String::from("Ya existe una lista con ese nombre"));
+            }
+            for (i, numero) in candidatos.iter().enumerate(){
+                if !eleccion.candidatos.iter().any(|c| c.numero_de_boleta == *numero){
+                    return Err(String::from("La lista contiene un candidato no aprobado en la eleccion"));
+                }
+                if candidatos[..i].contains(numero) || eleccion.listas.iter().any(|l| l.candidatos.contains(numero)){
+                    return Err(String::from("Un candidato no puede estar en mas de una lista"));
+                }
+            }
+            eleccion.listas.push(Lista::new(nombre, candidatos));
+            Ok(())
+        }
+
+        /// - Aprueba un reporte que pidio permiso para acceder al sistema. el parametro es usado para acceder al permiso por orden de llegada.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.aprobar_reporte(3);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn aprobar_reporte(&mut self,id:i16)->Result<(), String>{
+            if self.reporte_sin_permiso.len()<id as usize{
+                return Err(String::from("El id proporcionado no es valido"));
+            }
+            if id > 0 &&Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de administrador"));
+            }
+            
+            let account = self.reporte_sin_permiso.remove((id.checked_sub(1).unwrap())as usize);
+            self.reportes_con_permiso.push(account);
+            self.env().emit_event(ReporteResuelto{cuenta:account, aprobado:true});
+            Ok(())
+        }
+        
+
+        /// - Rechaza un reporte que pidio permiso para acceder al sistema. el parametro es usado para acceder al permiso por orden de llegada.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.rechazar_reporte(3);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn rechazar_reporte(&mut self,id:i16)->Result<(), String>{
+            if self.reporte_sin_permiso.len()<id as usize{
+                return Err(String::from("El id proporcionado no es valido"));
+            }
+            if id > 0 &&Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de administrador"));
+            }
+            
+            let account = self.reporte_sin_permiso.remove((id.checked_sub(1).unwrap())as usize);
+            if !self.reportes_rechazados.contains(&account){
+                self.reportes_rechazados.push(account);
+            }
+            self.env().emit_event(ReporteResuelto{cuenta:account, aprobado:false});
+            Ok(())
+        }
+
+        /// - Agrega al sistema la peticion de permiso de un reporte para poder acceder.
+        /// - Si la cuenta ya tiene un pedido pendiente, ya fue aprobada o fue rechazada no hace nada, igual que solicitar_acceso.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.agregar_accountid_de_reporte(accounts.bob);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn agregar_accountid_de_reporte(&mut self,id:AccountId){
+            if self.estado_de_reporte(id) != EstadoDeReporte::SinPedido{
+                return;
+            }
+            self.reporte_sin_permiso.push(id);
+            self.env().emit_event(ReporteSolicitado{cuenta:id});
+        }
+
+        /// - Lo llama un contrato de reporte para pedir acceso al sistema con su propia cuenta, queda pendiente hasta que el administrador lo resuelva.
+        /// - Si ya tiene un pedido pendiente o ya fue aprobado no se agrega otro pedido. Si fue rechazado devuelve error,
+        ///   un reporte rechazado no puede volver a pedir acceso.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.solicitar_acceso();
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn solicitar_acceso(&mut self)->Result<EstadoDeReporte, String>{
+            let cuenta = Self::env().caller();
+            match self.estado_de_reporte(cuenta){
+                EstadoDeReporte::SinPedido => {
+                    self.agregar_accountid_de_reporte(cuenta);
+                    Ok(EstadoDeReporte::Pendiente)
+                }
+                EstadoDeReporte::Rechazado => Err(String::from("El pedido de acceso del reporte fue rechazado por el administrador")),
+                estado => Ok(estado),
+            }
+        }
+
+        /// - Devuelve si el reporte con esa cuenta no pidio acceso, lo tiene pendiente, aprobado o rechazado. Lo puede consultar cualquier cuenta.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.estado_de_reporte(accounts.charlie);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn estado_de_reporte(&self, cuenta:AccountId)->EstadoDeReporte{
+            if self.reportes_con_permiso.contains(&cuenta){
+                EstadoDeReporte::Aprobado
+            }else if self.reporte_sin_permiso.contains(&cuenta){
+                EstadoDeReporte::Pendiente
+            }else if self.reportes_rechazados.contains(&cuenta){
+                EstadoDeReporte::Rechazado
+            }else{
+                EstadoDeReporte::SinPedido
+            }
+        }
+
+        /// - Obtiene un usuario del sistema.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.obtener_usuario(1);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn obtener_usuario(&self, id_usuario:i16)->Option<Usuario>{
+            if Self::env().caller() ==self.admin.accountid && id_usuario<=(self.usuarios_registrados.len() as i16){
+                return Some(self.usuarios_registrados.get(id_usuario.checked_sub(1).unwrap() as usize).unwrap().clone());
+            }
+            None
+        }
+
+        ///Devuelve una eleccion, util para el reporte.
+        #[ink(message)]
+        pub fn obtener_eleccion(&self, eleccion_id:i16)->Option<Eleccion>{
+            if Self::env().caller() ==self.admin.accountid && eleccion_id!=0 && eleccion_id<=self.elecciones.len() as i16{
+                let elec = self.elecciones.get(eleccion_id.checked_sub(1).unwrap() as usize).unwrap();
+                return Some(elec.clone())
+            }
+            None
+        }
+
+        /// - obtiene todos los usuarios registrados en el sistema.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.obtener_usuarios_registrados();
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn obtener_usuarios_registrados(&self)-> Result<Vec<Usuario>,String>{
+            if Self::env().caller() ==self.admin.accountid{
+                Ok(self.usuarios_registrados.clone())
+            }else{
+                Err(String::from("No tiene permiso de administrador"))
+            }
+        }
+
+        /// - obtiene todas las elecciones en el sistema.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.obtener_todas_las_elecciones();
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn obtener_todas_las_elecciones(&self)-> Result<Vec<Eleccion>,String>{
+            if Self::env().caller() ==self.admin.accountid{
+                Ok(self.elecciones.clone())
+            }else{
+                Err(String::from("No tiene permiso de administrador"))
+            }
+        }
+
+        /// - Obtiene una pagina de los usuarios registrados, empezando desde la posicion desde (la primera es 0).
+        /// - Solo para el administrador, la cantidad por pagina va de 1 a 50.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.obtener_usuarios_paginado(0,20);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn obtener_usuarios_paginado(&self, desde:u32, cantidad:u32)->Result<Pagina<Usuario>, String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            Pagina::armar(&self.usuarios_registrados, desde, cantidad, |_| true)
+        }
+
+        /// - Obtiene una pagina de las elecciones que cumplen el filtro, empezando desde la posicion desde (la primera es 0).
+        /// - Solo para el administrador, la cantidad por pagina va de 1 a 50.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::{SistemaDeVotacion, FiltroDeElecciones, EstadoDeEleccion};
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let filtro = FiltroDeElecciones::new(Some(EstadoDeEleccion::Abierta),None,None,None);
+        /// let r = sistema.obtener_elecciones_paginado(0,20,filtro);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn obtener_elecciones_paginado(&self, desde:u32, cantidad:u32, filtro:FiltroDeElecciones)->Result<Pagina<Eleccion>, String>{
+            if Self::env().caller() !=self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            let ahora = Self::env().block_timestamp();
+            Pagina::armar(&self.elecciones, desde, cantidad, |eleccion| filtro.cumple(eleccion, ahora))
+        }
+
+        ///devuelve true si el año es biciesto.
+        fn es_bisiesto(anio: i32) -> bool {
+            (anio % 4 == 0 && anio % 100 != 0) || (anio % 400 == 0)
+        }
+        
+        /// Devuelve la cantidad de dias del mes pasado por parametro.
+        fn dias_en_mes(anio: i32, mes: i32) -> i32 {
             match mes {
                 1 => 31,
                 2 => if Self::es_bisiesto(anio) { 29 } else { 28 },
@@ -477,751 +2760,2560 @@ pub mod sistema_de_votacion {
                 _ => 0,
             }
         }
-        
-        ///Calcula los dias desde 1970.
-        fn dias_desde_1970_hasta_anio(anio: i32) -> Result<i32, &'static str> {
-            let mut dias: i32 = 0;
-            for a in 1970..anio {
-                dias = dias.checked_add(if Self::es_bisiesto(a) { 366 } else { 365 })
-                    .ok_or("Overflow in dias_desde_1970_hasta_anio")?;
+        
+        ///Calcula los dias desde 1970.
+        fn dias_desde_1970_hasta_anio(anio: i32) -> Result<i32, &'static str> {
+            let mut dias: i32 = 0;
+            for a in 1970..anio {
+                dias = dias.checked_add(if Self::es_bisiesto(a) { 366 } else { 365 })
+                    .ok_or("Overflow in dias_desde_1970_hasta_anio")?;
+            }
+            Ok(dias)
+        }
+        
+        ///Calcula el timestamp en milisegundos.
+        fn timestamp(anio: i32, mes: i32, dia: i32, hora: i32, minuto: i32, segundo: i32, milisegundos: i32) -> Result<i64, String> {
+            let dias_desde_1970 = Self::dias_desde_1970_hasta_anio(anio)?;
+
+            if anio<1970{
+                return Err(String::from("Año invalido"));
+            }
+
+            let mut dias_hasta_mes: i32 = 0;
+            for m in 1..mes {
+                dias_hasta_mes = dias_hasta_mes.checked_add(Self::dias_en_mes(anio, m))
+                    .ok_or("Overflow in dias_hasta_mes")?;
+            }
+
+            let dias_totales = dias_desde_1970
+                .checked_add(dias_hasta_mes)
+                .and_then(|v| v.checked_add(dia.checked_sub(1).ok_or("Underflow in dia").ok()?))
+                .ok_or("Overflow in dias_totales")?;
+
+            let milisegundos_totales = (dias_totales as i64)
+                .checked_mul(24 * 3600 * 1000) // 24 hours * 3600 seconds * 1000 milliseconds
+                .and_then(|v| v.checked_add((hora as i64).checked_mul(3600 * 1000).ok_or("Overflow in hora").ok()?))
+                .and_then(|v| v.checked_add((minuto as i64).checked_mul(60 * 1000).ok_or("Overflow in minuto").ok()?))
+                .and_then(|v| v.checked_add((segundo as i64).checked_mul(1000).ok_or("Overflow in segundo").ok()?))
+                .and_then(|v| v.checked_add((milisegundos as i64).checked_mul(1).ok_or("Overflow in milisegundos").ok()?))
+                .ok_or("Overflow in milisegundos_totales")?;
+                
+            Ok(milisegundos_totales)
+        }
+
+        //METODOS DE USUARIO
+        
+        ///Crea un nuevo usuario.
+        /// EJEMPLO
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.registrar_usuario(String::from("nombre"),String::from("apellido"),String::from("dni"));
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn registrar_usuario(&mut self, nombre:String, apellido:String, dni:String){
+            let usuario = Usuario::new(nombre, apellido, dni,Self::env().caller() , self.elecciones.len() as i16);
+            self.usuarios_registrados.push(usuario);
+            self.env().emit_event(UsuarioRegistrado{id_usuario:self.usuarios_registrados.len() as i16, cuenta:Self::env().caller()});
+        }
+        
+        /// - si es_votante es true lo inscribe como votante, en caso contrario como candidato y ademas cambia a true
+        /// - la participacion del usuario en dicha eleccion para que no pueda inscribirse 2 veces en misma eleccion.
+        /// - los candidatos pueden enviar su perfil (partido, plataforma y hash del contenido), si no lo envian queda vacio.
+        /// - al candidato se le asigna el proximo numero de boleta de la eleccion.
+        /// - los candidatos deben transferir exactamente el deposito de la eleccion, los votantes no transfieren nada.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.registrar_usuario(String::from("nombre"),String::from("apellido"),String::from("dni"));
+        /// let r = sistema.postulacion_de_usuario(1,1,true,None);
+        /// ```
+        /// 
+        #[ink(message, payable)]
+        pub fn postulacion_de_usuario(&mut self, id_usuario:i16, id_eleccion:i16, es_votante:bool, perfil:Option<PerfilCandidato>)->Result<(), String> {
+            self.postular(id_usuario, id_eleccion, es_votante, 1, perfil)
+        }
+
+        /// - Postula al usuario como candidato a uno de los cargos de la eleccion.
+        /// - el id_cargo es la posicion del cargo en la eleccion, el 1 es el cargo con el que se creo.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.registrar_usuario(String::from("nombre"),String::from("apellido"),String::from("dni"));
+        /// let r = sistema.postulacion_a_cargo(1,1,2,None);
+        /// ```
+        ///
+        #[ink(message, payable)]
+        pub fn postulacion_a_cargo(&mut self, id_usuario:i16, id_eleccion:i16, id_cargo:i16, perfil:Option<PerfilCandidato>)->Result<(), String> {
+            self.postular(id_usuario, id_eleccion, false, id_cargo, perfil)
+        }
+
+        ///Inscribe al usuario como votante o como candidato al cargo indicado.
+        #[allow(clippy::bool_comparison)]
+        fn postular(&mut self, id_usuario:i16, id_eleccion:i16, es_votante:bool, id_cargo:i16, perfil:Option<PerfilCandidato>)->Result<(), String> {
+            let id_user = id_usuario.checked_sub(1).unwrap();
+            let id_elec = id_eleccion.checked_sub(1).unwrap();
+
+            if id_usuario as usize > self.usuarios_registrados.len() || id_usuario<0{
+                return Err(String::from("Id de usuario invalido"));
+            }
+
+            if Self::env().caller() !=self.usuarios_registrados[id_user as usize].datos.accountid{
+                return Err(String::from("No es el usuario quien intenta votar "));
+            }
+            
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No Existe la eleccion "));
+            }
+            self.verificar_no_pausada(id_eleccion)?;
+
+            if !self.existe_usuario(id_usuario){
+                return Err(String::from("No existe usuario"));
+            }
+            
+            if Self::env().block_timestamp() >= (self.elecciones[id_elec as usize].inicio as u64){
+                return Err(String::from(" No se puede inscribir despues de la fecha de inicio de la eleccion "));
+            }
+            
+                
+            let eleccion = self.elecciones.get_mut(id_elec as usize);
+            let eleccion = match eleccion{
+                Some(dato) => dato,
+                None => return Err(String::from("No tiene permiso de administrador")),
+            };
+            let usuario = self.usuarios_registrados.get_mut(id_user as usize);
+            let usuario = match usuario{
+                Some(dato) => dato,
+                None => return Err(String::from("No tiene permiso de administrador")),
+            };
+
+            if usuario.participacion[id_elec as usize]!=false{
+                return Err(String::from(" No se puede inscribir porque ya esta participando "));
+            }
+
+            let transferido = Self::env().transferred_value();
+            if es_votante{
+                if transferido != 0{
+                    return Err(String::from("Los votantes no pagan deposito"));
+                }
+                eleccion.postulados_a_votantes.push(Votante::new(usuario.clone().datos));
+            }else{
+                if transferido != eleccion.deposito{
+                    return Err(String::from("El monto transferido no es el deposito de la eleccion"));
+                }
+                if eleccion.tipo == TipoDeEleccion::Referendum{
+                    return Err(String::from("En un referendum no hay candidatos"));
+                }
+                if id_cargo<1 || id_cargo as usize > eleccion.cargos.len(){
+                    return Err(String::from("No existe el cargo en la eleccion"));
+                }
+                let perfil = perfil.unwrap_or_default();
+                perfil.validar()?;
+                let mut candidato = Candidato::new(usuario.clone().datos);
+                candidato.numero_de_boleta = eleccion.proximo_numero_de_boleta;
+                candidato.id_cargo = id_cargo;
+                candidato.perfil = perfil;
+                eleccion.proximo_numero_de_boleta = eleccion.proximo_numero_de_boleta.checked_add(1).unwrap();
+                eleccion.postulados_a_candidatos.push(candidato);
+                if transferido>0{
+                    eleccion.depositos.push(Deposito{dato:usuario.datos.clone(), monto:transferido, estado:EstadoDeDeposito::Retenido});
+                }
+            }
+            
+            self.usuarios_registrados[id_user as usize].participacion[id_elec as usize] = true;
+            self.env().emit_event(UsuarioPostulado{id_eleccion, id_usuario, es_votante, id_cargo});
+            Ok(())
+
+        }
+
+        /// - Reemplaza el perfil de un candidato, ya sea que este postulado o aprobado.
+        /// - Solo lo puede hacer el mismo usuario y antes de que empiece la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::{SistemaDeVotacion, PerfilCandidato};
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let perfil = PerfilCandidato::new(String::from("partido"),String::from("propuestas"),None);
+        /// let r = sistema.editar_perfil_de_candidato(1,1,perfil);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn editar_perfil_de_candidato(&mut self, id_usuario:i16, id_eleccion:i16, perfil:PerfilCandidato)->Result<(), String> {
+            if !self.existe_usuario(id_usuario){
+                return Err(String::from("No existe usuario"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No Existe la eleccion "));
+            }
+            self.verificar_no_pausada(id_eleccion)?;
+            let dato = self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone();
+            if Self::env().caller() != dato.accountid{
+                return Err(String::from("No tiene permiso de usuario "));
+            }
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("No se puede editar el perfil despues de la fecha de inicio de la eleccion"));
+            }
+            perfil.validar()?;
+
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            let candidato = eleccion.postulados_a_candidatos.iter_mut()
+                .chain(eleccion.candidatos.iter_mut())
+                .find(|c| c.dato == dato);
+            match candidato {
+                Some(candidato) => {
+                    candidato.perfil = perfil;
+                    Ok(())
+                }
+                None => Err(String::from("El usuario no es candidato en la eleccion con ese id")),
+            }
+        }
+
+        /// - Apela el rechazo de la postulacion del usuario, explicando el descargo.
+        /// - Solo lo puede hacer el mismo usuario, una sola vez y antes de que empiece la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.apelar_rechazo(1,1,String::from("Mi dni esta al dia"));
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn apelar_rechazo(&mut self, id_usuario:i16, id_eleccion:i16, descargo:String)->Result<(), String> {
+            if !self.existe_usuario(id_usuario){
+                return Err(String::from("No existe usuario"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No Existe la eleccion "));
+            }
+            self.verificar_no_pausada(id_eleccion)?;
+            let dato = self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone();
+            if Self::env().caller() != dato.accountid{
+                return Err(String::from("No tiene permiso de usuario "));
+            }
+            if !self.eleccion_no_empezada(id_eleccion){
+                return Err(String::from("No se puede apelar despues de la fecha de inicio de la eleccion"));
+            }
+            if descargo.len()>MAX_LARGO_DESCARGO{
+                return Err(String::from("El descargo es demasiado largo"));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            match eleccion.rechazados.iter_mut().find(|r| *r.postulante.get_dato() == dato){
+                Some(rechazo) if rechazo.apelacion == EstadoDeApelacion::SinApelar => {
+                    rechazo.apelacion = EstadoDeApelacion::Pendiente;
+                    rechazo.descargo = descargo;
+                    Ok(())
+                }
+                Some(_) => Err(String::from("El rechazo ya fue apelado")),
+                None => Err(String::from("El usuario no fue rechazado en la eleccion con ese id")),
+            }
+        }
+
+        /// - Devuelve una pagina del catalogo publico de elecciones, empezando desde la posicion desde (la primera es 0).
+        /// - Lo puede consultar cualquier cuenta, la cantidad por pagina va de 1 a 50.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.catalogo_de_elecciones(0,20);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn catalogo_de_elecciones(&self, desde:u32, cantidad:u32)->Result<Pagina<VistaDeEleccion>, String> {
+            let ahora = Self::env().block_timestamp();
+            Pagina::armar_con(&self.elecciones, desde, cantidad, |eleccion| Some(eleccion.get_vista(ahora)))
+        }
+
+        /// - Devuelve la vista publica de una eleccion, sin la identidad de los votantes.
+        /// - Lo puede consultar cualquier cuenta.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.vista_de_eleccion(1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn vista_de_eleccion(&self, id_eleccion:i16)->Result<VistaDeEleccion, String> {
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe la elecion"));
+            }
+            let eleccion = self.elecciones.get(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            Ok(eleccion.get_vista(Self::env().block_timestamp()))
+        }
+
+        /// - Devuelve la ficha publica de los candidatos aprobados de una eleccion, ordenada por numero de boleta.
+        /// - Lo puede consultar cualquier cuenta, no incluye dni, accountid ni votos.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.candidatos_de_eleccion(1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn candidatos_de_eleccion(&self, id_eleccion:i16)->Result<Vec<FichaDeCandidato>, String> {
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe la elecion"));
+            }
+            let eleccion = self.elecciones.get(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            let mut fichas:Vec<FichaDeCandidato> = eleccion.candidatos.iter().map(|c| c.get_ficha()).collect();
+            fichas.sort_unstable_by_key(|f| f.numero_de_boleta);
+            Ok(fichas)
+        }
+
+        /// - Devuelve, para cada usuario registrado con la cuenta que llama, las elecciones en las que participa.
+        /// - Indica el rol, si la postulacion esta pendiente, aprobada o rechazada y si ya voto.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.mi_estado();
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn mi_estado(&self)->Vec<EstadoDeParticipacion>{
+            let caller = Self::env().caller();
+            let mut estados = Vec::new();
+            for (u, usuario) in self.usuarios_registrados.iter().enumerate(){
+                if usuario.datos.accountid != caller{
+                    continue;
+                }
+                for (e, participa) in usuario.participacion.iter().enumerate(){
+                    if !*participa{
+                        continue;
+                    }
+                    let eleccion = &self.elecciones[e];
+                    if let Some((rol, validacion, voto_emitido)) = eleccion.get_participacion(&usuario.datos){
+                        estados.push(EstadoDeParticipacion{
+                            id_usuario:(u as i16).checked_add(1).unwrap(),
+                            id_eleccion:(e as i16).checked_add(1).unwrap(),
+                            cargo:eleccion.cargo.clone(),
+                            rol,
+                            validacion,
+                            voto_emitido,
+                        });
+                    }
+                }
+            }
+            estados
+        }
+
+        /// - el id_usuario es la posicion del votante en el vector de usuarios registrados en el sistema de votacion.
+        /// - el id_candidato es la posicion del candidato en el vector candidatos adentro de la eleccion.
+        /// - el nonce lo elige el votante y entra en el comprobante, con el puede demostrar que el comprobante es suyo.
+        /// - devuelve el comprobante del voto, que despues se puede controlar con verificar_comprobante.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.votar_a_candidato(1,1,2, [0x07; 32].into());
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn votar_a_candidato(&mut self, id_usuario:i16, id_eleccion:i16, id_candidato:i16, nonce:Hash)->Result<Hash, String> {
+            let indice_votante = self.verificar_votante(id_usuario, id_eleccion)?;
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+
+            if eleccion.cargos.len()>1{
+                return Err(String::from("La eleccion tiene varios cargos, se debe votar con votar_boleta"));
+            }
+            if id_candidato<1 || (eleccion.candidatos.len() as i16) < id_candidato{
+                return Err(String::from("no existe el candidato "));
+            }
+
+            let candidato = &mut eleccion.candidatos[id_candidato.checked_sub(1).unwrap() as usize];
+            candidato.cant_votos = candidato.cant_votos.checked_add(1).unwrap();
+            Ok(self.registrar_voto(id_eleccion, indice_votante, nonce))
+        }
+
+        /// - Vota en una sola transaccion a un candidato por cada cargo de la eleccion.
+        /// - numeros_de_boleta tiene un numero de boleta por cargo, en el mismo orden que los cargos de la eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.votar_boleta(1,1,vec![1,3], [0x07; 32].into());
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn votar_boleta(&mut self, id_usuario:i16, id_eleccion:i16, numeros_de_boleta:Vec<i16>, nonce:Hash)->Result<Hash, String> {
+            let indice_votante = self.verificar_votante(id_usuario, id_eleccion)?;
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+
+            if numeros_de_boleta.len() != eleccion.cargos.len(){
+                return Err(String::from("La boleta debe tener un candidato por cada cargo"));
+            }
+            let mut elegidos:Vec<usize> = Vec::new();
+            for (i, numero) in numeros_de_boleta.iter().enumerate(){
+                let id_cargo = (i as i16).checked_add(1).unwrap();
+                match eleccion.candidatos.iter().position(|c| c.numero_de_boleta == *numero && c.id_cargo == id_cargo){
+                    Some(posicion) => elegidos.push(posicion),
+                    None => return Err(String::from("La boleta tiene un candidato que no se postulo a ese cargo")),
+                }
+            }
+            for posicion in elegidos{
+                let candidato = &mut eleccion.candidatos[posicion];
+                candidato.cant_votos = candidato.cant_votos.checked_add(1).unwrap();
+            }
+            Ok(self.registrar_voto(id_eleccion, indice_votante, nonce))
+        }
+
+        /// - Vota una de las opciones de un referendum.
+        /// - el id_opcion es la posicion de la opcion en el referendum.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.votar_opcion(1,1,2, [0x07; 32].into());
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn votar_opcion(&mut self, id_usuario:i16, id_eleccion:i16, id_opcion:i16, nonce:Hash)->Result<Hash, String> {
+            let indice_votante = self.verificar_votante(id_usuario, id_eleccion)?;
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+
+            if eleccion.tipo != TipoDeEleccion::Referendum{
+                return Err(String::from("La eleccion no es un referendum"));
+            }
+            if id_opcion<1 || (eleccion.opciones.len() as i16) < id_opcion{
+                return Err(String::from("No existe la opcion"));
+            }
+
+            let opcion = &mut eleccion.opciones[id_opcion.checked_sub(1).unwrap() as usize];
+            opcion.cant_votos = opcion.cant_votos.checked_add(1).unwrap();
+            Ok(self.registrar_voto(id_eleccion, indice_votante, nonce))
+        }
+
+        /// - Vota a una lista completa en lugar de a un candidato individual.
+        /// - el id_lista es la posicion de la lista en el vector listas adentro de la eleccion.
+        /// - no se puede usar en elecciones con varios cargos, porque saltearia el voto por cargo de votar_boleta.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.votar_a_lista(1,1,1, [0x07; 32].into());
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn votar_a_lista(&mut self, id_usuario:i16, id_eleccion:i16, id_lista:i16, nonce:Hash)->Result<Hash, String> {
+            let indice_votante = self.verificar_votante(id_usuario, id_eleccion)?;
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+
+            if eleccion.cargos.len()>1{
+                return Err(String::from("La eleccion tiene varios cargos, se debe votar con votar_boleta"));
+            }
+            if id_lista<1 || (eleccion.listas.len() as i16) < id_lista{
+                return Err(String::from("No existe la lista"));
+            }
+
+            let lista = &mut eleccion.listas[id_lista.checked_sub(1).unwrap() as usize];
+            lista.cant_votos = lista.cant_votos.checked_add(1).unwrap();
+            Ok(self.registrar_voto(id_eleccion, indice_votante, nonce))
+        }
+
+        /// - Emite un voto en blanco: cuenta como voto emitido pero no suma a ningun candidato, lista u opcion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.votar_en_blanco(1,1, [0x07; 32].into());
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn votar_en_blanco(&mut self, id_usuario:i16, id_eleccion:i16, nonce:Hash)->Result<Hash, String> {
+            let indice_votante = self.verificar_votante(id_usuario, id_eleccion)?;
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            eleccion.votos_en_blanco = eleccion.votos_en_blanco.checked_add(1).unwrap();
+            Ok(self.registrar_voto(id_eleccion, indice_votante, nonce))
+        }
+
+        /// - Congela el resultado de una eleccion terminada y guarda el hash blake2x256 de su codificacion SCALE.
+        /// - Desde ese momento los reportes leen el resultado final y la eleccion no se puede eliminar, pausar ni extender.
+        /// - Lo puede llamar cualquier cuenta despues del fin, una sola vez, y devuelve el hash del resultado.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.finalizar_eleccion(1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn finalizar_eleccion(&mut self, id_eleccion:i16)->Result<Hash, String> {
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            self.verificar_no_pausada(id_eleccion)?;
+            let ahora = Self::env().block_timestamp();
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if eleccion.resultado_final.is_some(){
+                return Err(String::from("La eleccion ya esta finalizada"));
+            }
+            if ahora <= eleccion.fin as u64{
+                return Err(String::from("La eleccion todavia no termino"));
+            }
+            let resultado = eleccion.armar_resultado_final(ahora);
+            let mut salida = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&resultado, &mut salida);
+            let hash = Hash::from(salida);
+            eleccion.resultado_final = Some(resultado);
+            eleccion.hash_del_resultado = Some(hash);
+            self.env().emit_event(EleccionFinalizada{id_eleccion, hash_del_resultado:hash});
+            Ok(hash)
+        }
+
+        /// - Devuelve el resultado final de una eleccion finalizada junto con su hash, lo puede consultar cualquier cuenta.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.resultado_final(1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn resultado_final(&self, id_eleccion:i16)->Result<(ResultadoFinal, Hash), String> {
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            let eleccion = &self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize];
+            match (&eleccion.resultado_final, eleccion.hash_del_resultado){
+                (Some(resultado), Some(hash)) => Ok((resultado.clone(), hash)),
+                _ => Err(String::from("La eleccion no esta finalizada")),
+            }
+        }
+
+        /// - Devuelve solo el hash del resultado final, None si la eleccion no esta finalizada.
+        /// - Sirve para saber si un resumen guardado fuera del sistema sigue correspondiendo al resultado sin pedir la eleccion entera.
+        /// - Tiene los mismos permisos que obtener_reporte_de_eleccion, asi un reporte no sirve resumenes guardados sin estar aprobado.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.hash_del_resultado(1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn hash_del_resultado(&self, id_eleccion:i16)->Result<Option<Hash>, String> {
+            self.verificar_permiso_de_reporte()?;
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            Ok(self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize].hash_del_resultado)
+        }
+
+        /// - Define los auditores de la eleccion y cuantas certificaciones necesita el resultado final.
+        /// - Solo lo puede hacer el administrador antes de finalizar la eleccion; el umbral va de 1 a la cantidad de auditores.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.configurar_auditores(1,vec![accounts.bob,accounts.charlie],2);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn configurar_auditores(&mut self, id_eleccion:i16, auditores:Vec<AccountId>, umbral:u16)->Result<(), String> {
+            if Self::env().caller() != self.admin.accountid{
+                return Err(String::from("No tiene permiso de admin"));
+            }
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            if auditores.is_empty() || auditores.len()>MAX_AUDITORES{
+                return Err(String::from("Se deben indicar entre 1 y 32 auditores"));
+            }
+            if auditores.iter().enumerate().any(|(i, auditor)| auditores[..i].contains(auditor)){
+                return Err(String::from("Hay auditores repetidos"));
+            }
+            if umbral<1 || umbral as usize > auditores.len(){
+                return Err(String::from("El umbral debe estar entre 1 y la cantidad de auditores"));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            if eleccion.resultado_final.is_some(){
+                return Err(String::from("La eleccion esta finalizada"));
+            }
+            eleccion.auditores = auditores;
+            eleccion.umbral_de_certificacion = umbral;
+            Ok(())
+        }
+
+        /// - El auditor que llama certifica el resultado final de la eleccion.
+        /// - Cada auditor se expide una sola vez, certificando o disputando.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.certificar_resultado(1);
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn certificar_resultado(&mut self, id_eleccion:i16)->Result<EstadoDeCertificacion, String> {
+            self.verificar_auditor(id_eleccion)?;
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            eleccion.firmantes.push(Self::env().caller());
+            Ok(eleccion.get_estado_de_certificacion())
+        }
+
+        /// - El auditor que llama disputa el resultado final de la eleccion indicando el motivo.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.disputar_resultado(1,String::from("Faltan votos de la mesa 3"));
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn disputar_resultado(&mut self, id_eleccion:i16, motivo:String)->Result<EstadoDeCertificacion, String> {
+            self.verificar_auditor(id_eleccion)?;
+            if motivo.is_empty() || motivo.len()>MAX_LARGO_MOTIVO_DE_DISPUTA{
+                return Err(String::from("El motivo de la disputa debe tener entre 1 y 512 caracteres"));
+            }
+            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+            eleccion.disputas.push(Disputa{auditor:Self::env().caller(), motivo});
+            Ok(eleccion.get_estado_de_certificacion())
+        }
+
+        ///Controla que el llamador sea auditor de una eleccion finalizada y que todavia no se haya expedido.
+        fn verificar_auditor(&self, id_eleccion:i16)->Result<(), String> {
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            let caller = Self::env().caller();
+            let eleccion = &self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize];
+            if !eleccion.auditores.contains(&caller){
+                return Err(String::from("No es auditor de la eleccion"));
+            }
+            if eleccion.resultado_final.is_none(){
+                return Err(String::from("La eleccion no esta finalizada"));
+            }
+            if eleccion.firmantes.contains(&caller) || eleccion.disputas.iter().any(|d| d.auditor == caller){
+                return Err(String::from("El auditor ya se expidio sobre el resultado"));
+            }
+            Ok(())
+        }
+
+        /// Controla que el usuario pueda votar ahora en la eleccion y devuelve su posicion en el vector de votantes.
+        fn verificar_votante(&self, id_usuario:i16, id_eleccion:i16)->Result<usize, String>{
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No se enceuntra la eleccion "));
+            }
+            self.verificar_no_pausada(id_eleccion)?;
+            if !self.existe_usuario(id_usuario){
+                return Err(String::from("No existe usuario"));
+            }
+            let eleccion = &self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize];
+            let dato_votante = &self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos;
+
+            if Self::env().caller() != dato_votante.accountid {
+                return Err(String::from("No tiene permiso de usuario "));
+            }
+
+            if (Self::env().block_timestamp() < eleccion.inicio as u64) || (Self::env().block_timestamp() > eleccion.fin as u64) {
+                let block_timestamp = Self::env().block_timestamp();
+                let mut error_message = String::from("Votación fuera de fecha, timestamp del block actual: ");
+                error_message.push_str(&block_timestamp.to_string());
+                return Err(error_message);
+            }
+
+            match eleccion.votantes.iter().position(|e| e.dato == *dato_votante) {
+                Some(index) if eleccion.votantes[index].estado_del_voto => Err(String::from("El votante ya voto")),
+                Some(index) => Ok(index),
+                None => Err(String::from("No contiene este votante ")),
+            }
+        }
+
+        /// Marca que el votante ya emitio su voto en la eleccion y devuelve su comprobante.
+        /// El comprobante es el hash de (clave de la eleccion, nulificador del votante, nonce del votante), no dice a quien voto.
+        fn registrar_voto(&mut self, id_eleccion:i16, indice_votante:usize, nonce:Hash)->Hash{
+            let ahora = Self::env().block_timestamp();
+            let eleccion = &mut self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize];
+            eleccion.votantes[indice_votante].estado_del_voto = true;
+
+            let transcurrido = ahora.saturating_sub(eleccion.inicio as u64);
+            let desde = (eleccion.inicio as u64).checked_add(transcurrido.checked_sub(transcurrido.checked_rem(eleccion.intervalo_de_votos).unwrap()).unwrap()).unwrap();
+            match eleccion.votos_por_intervalo.last_mut(){
+                Some((inicio, votos)) if *inicio == desde => *votos = votos.checked_add(1).unwrap(),
+                _ => eleccion.votos_por_intervalo.push((desde, 1)),
+            }
+
+            let mut nulificador = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&(eleccion.clave, eleccion.votantes[indice_votante].dato.accountid), &mut nulificador);
+            let mut comprobante = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&(eleccion.clave, nulificador, nonce), &mut comprobante);
+            let comprobante = Hash::from(comprobante);
+
+            eleccion.acumulador_de_comprobantes = Self::encadenar(eleccion.acumulador_de_comprobantes, comprobante);
+            eleccion.cantidad_de_comprobantes = eleccion.cantidad_de_comprobantes.checked_add(1).unwrap();
+            self.comprobantes.insert((eleccion.clave, comprobante), &eleccion.cantidad_de_comprobantes);
+            self.comprobantes_por_posicion.insert((eleccion.clave, eleccion.cantidad_de_comprobantes), &comprobante);
+            self.acumuladores.insert((eleccion.clave, eleccion.cantidad_de_comprobantes), &eleccion.acumulador_de_comprobantes);
+            self.env().emit_event(VotoEmitido{id_eleccion, comprobante});
+            comprobante
+        }
+
+        /// Siguiente eslabon de la cadena de comprobantes: el hash de (acumulador, comprobante).
+        fn encadenar(acumulador:Hash, comprobante:Hash)->Hash{
+            let mut salida = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&(acumulador, comprobante), &mut salida);
+            Hash::from(salida)
+        }
+
+        /// - Devuelve la prueba de inclusion del comprobante de voto, o None si no esta registrado en la eleccion.
+        /// - Recalcula la cadena desde el acumulador previo al comprobante, con el y los comprobantes posteriores, y la compara
+        ///   con el acumulador final: el del resultado final si la eleccion esta finalizada, si no el actual.
+        ///   Si no coincide devuelve error y el comprobante no se da por incluido.
+        /// - Lo puede consultar cualquier cuenta y no revela a quien corresponde ni que se voto.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.verificar_comprobante(1,[0x01; 32].into());
+        /// ```
+        ///
+        #[ink(message)]
+        pub fn verificar_comprobante(&self, id_eleccion:i16, comprobante:Hash)->Result<Option<InclusionDeComprobante>, String>{
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe una eleccion con ese id"));
+            }
+            let eleccion = &self.elecciones[id_eleccion.checked_sub(1).unwrap() as usize];
+            let (cantidad_de_comprobantes, acumulador_final) = match &eleccion.resultado_final{
+                Some(resultado) => (resultado.cantidad_de_comprobantes, resultado.acumulador_de_comprobantes),
+                None => (eleccion.cantidad_de_comprobantes, eleccion.acumulador_de_comprobantes),
+            };
+            let posicion = match self.comprobantes.get((eleccion.clave, comprobante)){
+                Some(posicion) if posicion <= cantidad_de_comprobantes => posicion,
+                _ => return Ok(None),
+            };
+            let no_coincide = String::from("La cadena de comprobantes no coincide con el acumulador de la eleccion");
+            let acumulador_previo = match posicion{
+                1 => Hash::default(),
+                _ => self.acumuladores.get((eleccion.clave, posicion.checked_sub(1).unwrap())).ok_or(no_coincide.clone())?,
+            };
+            let acumulador = Self::encadenar(acumulador_previo, comprobante);
+            let mut siguiente = acumulador;
+            for posterior in posicion.checked_add(1).unwrap()..=cantidad_de_comprobantes{
+                let comprobante_posterior = self.comprobantes_por_posicion.get((eleccion.clave, posterior)).ok_or(no_coincide.clone())?;
+                siguiente = Self::encadenar(siguiente, comprobante_posterior);
+            }
+            if siguiente != acumulador_final{
+                return Err(no_coincide);
+            }
+            Ok(Some(InclusionDeComprobante{
+                posicion,
+                acumulador_previo,
+                acumulador,
+                cantidad_de_comprobantes,
+                acumulador_final,
+            }))
+        }
+
+        //METODOS DEL REPORTE
+
+        /// - Devuelve los datos de una eleccion, solo si esta esta cerrada y tiene los permisos necesarios.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.obtener_reporte_de_eleccion(3);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn obtener_reporte_de_eleccion(&self, id_eleccion:i16)->Result<Eleccion, String>{
+
+            self.verificar_permiso_de_reporte()?;
+
+            if !self.existe_eleccion(id_eleccion){
+                return Err(String::from("No existe la elecion"));
+            }
+
+            let eleccion = self.elecciones.get(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+
+            if Self::env().block_timestamp()<eleccion.fin as u64{
+                return Err(String::from("La eleccion aun no ha cerrado"));
+            }
+            
+            Ok(eleccion.clone())
+        }
+
+        /// - Devuelve una pagina con el resumen de las elecciones que ya cerraron junto con su id, en el orden en que se crearon.
+        /// - desde es la posicion desde la que se empieza a buscar y cantidad el maximo de elecciones de la pagina (entre 1 y 50).
+        /// - Tiene los mismos permisos que obtener_reporte_de_eleccion.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let sistema = SistemaDeVotacion::new();
+        /// let r = sistema.obtener_resumenes_de_elecciones_cerradas(0, 10);
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn obtener_resumenes_de_elecciones_cerradas(&self, desde:u32, cantidad:u32)->Result<Pagina<ResumenDeEleccionCerrada>, String>{
+            self.verificar_permiso_de_reporte()?;
+            let ahora = Self::env().block_timestamp();
+            Pagina::armar_con(&self.elecciones, desde, cantidad, |eleccion| {
+                if ahora >= eleccion.fin as u64 {Some(eleccion.armar_resumen_cerrado())} else {None}
+            })
+        }
+
+        ///Controla que quien llama sea el administrador o un reporte aprobado, si no el error dice en que quedo su pedido de acceso.
+        fn verificar_permiso_de_reporte(&self)->Result<(), String>{
+            let cuenta = Self::env().caller();
+            if cuenta == self.admin.accountid{
+                return Ok(());
+            }
+            match self.estado_de_reporte(cuenta){
+                EstadoDeReporte::Aprobado => Ok(()),
+                EstadoDeReporte::Pendiente => Err(String::from("El pedido de acceso del reporte esta pendiente de aprobacion por el administrador del sistema")),
+                EstadoDeReporte::Rechazado => Err(String::from("El pedido de acceso del reporte fue rechazado por el administrador del sistema")),
+                EstadoDeReporte::SinPedido => Err(String::from("El reporte no pidio acceso al sistema, llame a solicitar_acceso")),
+            }
+        }
+
+        /// - Devuelve true si el reporte esta habilitado para acceder al sistema.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.esta_reporte_aprobado(accounts.charlie);
+        /// ```
+        /// 
+        pub fn esta_reporte_aprobado(&self,id:AccountId)->bool{
+            if self.reportes_con_permiso.contains(&id){
+                return true
+            }
+
+            false
+        }
+
+        /// - Devielve los accountids de los reportes que estan aprobados.
+        /// - EJEMPLO:
+        /// ```
+        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
+        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        /// let mut sistema = SistemaDeVotacion::new();
+        /// let r = sistema.obtener_reportes_aprobados();
+        /// ```
+        /// 
+        #[ink(message)]
+        pub fn obtener_reportes_aprobados(&self)->Result<Vec<AccountId>,String>{
+            let account = Self::env().caller();
+            if account != self.admin.accountid && !self.reportes_con_permiso.contains(&account){
+                return Err(String::from("No tiene permisos"));
+            }
+            let reporte=self.reportes_con_permiso.clone();
+            Ok(reporte)
+        }
+        
+    }
+
+    #[cfg(test)]
+    mod tests {
+        
+        use super::*;
+        
+
+        #[ink::test]
+        fn ceder_admin_con_permiso_para_hacerlo(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.ceder_admin(accounts.bob);
+            assert!(res.is_ok());
+            assert_eq!(sistema.admin.accountid, accounts.bob);
+        }
+
+        #[ink::test]
+        fn intentar_ceder_admin_sin_permisos(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let res = sistema.ceder_admin(accounts.charlie);
+            assert!(res.is_err());
+            assert_eq!(sistema.admin.accountid,accounts.alice);
+        }
+
+        #[allow(clippy::needless_borrow)]
+        #[ink::test]
+        fn crear_eleccion_admin_invalido() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 3, 2024, 20, 3, 2024);
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
+            }
+            assert!(matches!(res, Err(ref e) if e == "No tienes permiso para crear una elección"));
+        }
+        
+        #[ink::test]
+        fn instanciar_sistema_de_votacion_y_probar_valores_iniciales(){
+            let sistema = SistemaDeVotacion::new();
+            //Prueba el AccountId guardado con uno capturado del ambiente (entiendo que deberia ser el mismo)
+            assert_eq!(sistema.admin.accountid, ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+            assert_eq!(sistema.elecciones.len(),0);
+            assert_eq!(sistema.usuarios_registrados.len(),0);
+        }
+        #[ink::test]
+        fn instanciar_sistema_de_votacion_y_probar_valores_iniciales_otro_account_de_admin(){
+            let sistema = SistemaDeVotacion::new();
+            //Prueba el AccountId guardado con uno capturado del ambiente (entiendo que No deberia ser el mismo)
+            assert_ne!(sistema.admin.accountid, ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().bob);
+            assert_eq!(sistema.elecciones.len(),0);
+            assert_eq!(sistema.usuarios_registrados.len(),0);
+        }
+
+        #[allow(unused_variables, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn crear_eleccion_valida(){
+            let mut sistema = SistemaDeVotacion::new();            
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 01, 2024, 20, 02, 2024);
+            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 03, 2024, 20, 04, 2024);
+            assert!(res.is_ok());
+            assert_eq!(sistema.elecciones.len(),2);
+        }
+        #[allow(clippy::let_unit_value, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn eliminar_eleccion_valida(){
+            let mut sistema = SistemaDeVotacion::new();            
+            let _ = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 01, 2024, 20, 02, 2024);
+            let _ = sistema.crear_eleccion(String::from("CEO de X"), 15, 03, 2024, 20, 04, 2024);
+            let _ = sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(1,2,false,None);
+            let res = sistema.eliminar_eleccion(2);
+            assert!(res.is_ok());
+            assert_eq!(sistema.elecciones.len(),1);
+            assert_eq!(sistema.usuarios_registrados[0].participacion.len(),1);
+        }
+        #[allow(unused_must_use, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn eliminar_eleccion_invalida(){
+            let mut sistema = SistemaDeVotacion::new();            
+            sistema.crear_eleccion(String::from("CEO de Intel"), 15, 01, 2024, 20, 02, 2024);
+            sistema.crear_eleccion(String::from("CEO de X"), 15, 03, 2024, 20, 04, 2024);
+            let res = sistema.eliminar_eleccion(5);
+            assert!(res.is_err());
+            assert_eq!(sistema.elecciones.len(),2);
+        }
+
+        #[allow(clippy::needless_borrow)]
+        #[ink::test]
+        fn rompe_crear_eleccion_timestamp_invalido(){
+            let mut sistema = SistemaDeVotacion::new();            
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 13, 19, 20, 14, 2024);
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
+            };
+            assert!(res.is_err());
+        }
+
+        #[allow(clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn crear_eleccion_fecha_invalida(){
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 05, 2024, 20, 03, 2024);
+            assert!(matches!(res,Err(e) if e == "La fecha de inicio debe ser anterior a la fecha de fin"))
+        }
+
+        #[ink::test]
+        fn crear_usuarios(){
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));
+            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));
+            assert_eq!(sistema.usuarios_registrados.len(),5);
+        }
+
+        #[allow(unused_variables, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn probando_acceso_con_getter_a_eleccion_en_la_posicion_deseada(){
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 05, 2024, 20, 06, 2024);
+            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 07, 2024, 20, 08, 2024);
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));
+            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));
+            let aux = sistema.obtener_eleccion(2);
+            assert_eq!(aux.unwrap().cargo,sistema.elecciones[1].cargo);
+        }
+
+        #[allow(unused_must_use, unused_variables)]
+        #[ink::test]
+        fn postulacion_de_usuario(){
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_720_210_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.postulacion_de_usuario(1,1,false,None);
+            assert_eq!(sistema.elecciones[0].postulados_a_candidatos[0].dato.nombre,String::from("Carlos"));
+        }
+
+        #[allow(unused_must_use, unused_variables)]
+        #[ink::test]
+        fn vallidacion_de_usuario(){
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 11, 2024, 20, 12, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.postulacion_de_usuario(1,1,false,None);
+            sistema.validar_usuario(1, 1, true);
+            assert_eq!(sistema.elecciones[0].candidatos[0].dato.nombre,String::from("Carlos"));
+        }
+
+        #[allow(unused_must_use, unused_variables, clippy::needless_borrow, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn probar_votar(){
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000);
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
+            let res = sistema.crear_eleccion(String::from("CEO de X"), 2, 03, 2024, 20, 07, 2024);//elec 2
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.postulacion_de_usuario(1,1,false,None);
+            sistema.postulacion_de_usuario(1,2,true,None);
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user2
+            sistema.postulacion_de_usuario(2,1,false,None);
+            sistema.postulacion_de_usuario(2,2,true,None);
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user3
+            sistema.postulacion_de_usuario(3,1,true,None);
+            sistema.postulacion_de_usuario(3,2,true,None);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user4
+            sistema.postulacion_de_usuario(4,1,true,None);
+            sistema.postulacion_de_usuario(4,2,false,None);
+            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));//user5
+            sistema.postulacion_de_usuario(5,1,true,None);
+            sistema.postulacion_de_usuario(5,2,false,None);
+            sistema.validar_usuario(1, 1, true);
+            sistema.validar_usuario(1, 2, true);
+            sistema.validar_usuario(2, 1, true);
+            sistema.validar_usuario(2, 2, true);
+            sistema.validar_usuario(3, 1, true);
+            sistema.validar_usuario(3, 2, true);
+            sistema.validar_usuario(4, 1, true);
+            sistema.validar_usuario(4, 2, true);
+            sistema.validar_usuario(5, 1, true);
+            sistema.validar_usuario(5, 2, true);
+            let user = sistema.obtener_usuario(1);
+            let usuarios_registrados = sistema.obtener_usuarios_registrados().unwrap();
+            let todas_las_elecciones = sistema.obtener_todas_las_elecciones().unwrap();
+            let reporte_de_eleccion = sistema.obtener_reporte_de_eleccion(1);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
+            let res = sistema.votar_a_candidato(3, 1, 2, Hash::default());
+            let res = sistema.votar_a_candidato(3, 1, 2, Hash::default());
+            let res = sistema.votar_a_candidato(1, 1, 2, Hash::default());
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
+            }
+            let res = sistema.votar_a_candidato(4, 1, 2, Hash::default());
+            let res = sistema.votar_a_candidato(5, 1, 2, Hash::default());
+            let cant = sistema.elecciones[0].get_cantidad_de_votos_emitidos();
+            assert_eq!(cant,3);
+            let cant_votos = sistema.elecciones[0].candidatos[0].get_cantidad_votos();
+                
+            assert_eq!(sistema.elecciones[0].candidatos[1].cant_votos,3);
+            assert_eq!(user.unwrap().datos.nombre,String::from("Carlos"));
+            assert_eq!(usuarios_registrados.len(),5);
+            assert_eq!(todas_las_elecciones.len(),2);
+            assert!(reporte_de_eleccion.is_err());
+        }
+
+        #[allow(unused_must_use, clippy::needless_borrow, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn verificar_que_un_usuario_no_se_puede_postular_a_ambos_roles_en_una_eleccion(){
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.postulacion_de_usuario(1,1,false,None);
+            let r = sistema.postulacion_de_usuario(1,1,true,None);
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
             }
-            Ok(dias)
+            assert!(r.is_err());
         }
-        
-        ///Calcula el timestamp en milisegundos.
-        fn timestamp(anio: i32, mes: i32, dia: i32, hora: i32, minuto: i32, segundo: i32, milisegundos: i32) -> Result<i64, String> {
-            let dias_desde_1970 = Self::dias_desde_1970_hasta_anio(anio)?;
 
-            if anio<1970{
-                return Err(String::from("Año invalido"));
+        #[allow(unused_must_use, unused_variables, clippy::manual_unwrap_or, clippy::needless_borrow)]
+        #[ink::test]
+        fn probar_votar_fuera_de_fecha(){
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 12, 2024, 20, 12, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.postulacion_de_usuario(1,1,false,None);
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user2
+            sistema.postulacion_de_usuario(2,1,false,None);
+            sistema.postulacion_de_usuario(2,2,true,None);
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user3
+            sistema.postulacion_de_usuario(3,1,true,None);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user4
+            sistema.postulacion_de_usuario(4,1,true,None);
+            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));//user5
+            sistema.postulacion_de_usuario(5,1,true,None);
+            sistema.validar_usuario(1, 1, true);
+            sistema.validar_usuario(2, 1, true);
+            sistema.validar_usuario(3, 1, true);
+            sistema.validar_usuario(4, 1, true);
+            sistema.validar_usuario(5, 1, true);
+            let timestamp_inicial = SistemaDeVotacion::timestamp(2024,12,15,0,0,0,0);
+            let timestamp_inicial = match timestamp_inicial {
+                Ok(dato) => dato,
+                _ => -1,
+            };
+            ink::env::debug_message(&format!("timestamp inicial: {}    ", timestamp_inicial));
+            let timestamp_final = SistemaDeVotacion::timestamp(2024,12,20,0,0,0,0);
+            let timestamp_final = match timestamp_final {
+                Ok(dato) => dato,
+                _ => -1,
+            };
+            ink::env::debug_message(&format!("timestamp final: {}    ", timestamp_final));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_734_000_000_000);
+            let timeblock = ink::env::block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::debug_message(&format!("Current block timestamp: {}   ", timeblock));
+            let res = sistema.votar_a_candidato(3, 1, 2, Hash::default());
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
             }
+            assert!(res.is_err()); 
+        }
 
-            let mut dias_hasta_mes: i32 = 0;
-            for m in 1..mes {
-                dias_hasta_mes = dias_hasta_mes.checked_add(Self::dias_en_mes(anio, m))
-                    .ok_or("Overflow in dias_hasta_mes")?;
-            }
+        #[ink::test]
+        fn test_elecciones_postulados_votantes(){
+            let num:i64=54;
+            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
+            let votantes=eleccion.get_postulados_a_votantes();
+            assert_eq!(votantes.len(),0);
+        }
+        #[ink::test]
+        fn test_elecciones_votantes(){
+            let num:i64=54;
+            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
+            let votantes=eleccion.get_votantes();
+            assert_eq!(votantes.len(),0);
+        }
+        #[ink::test]
+        fn test_elecciones_cantidad_de_votantes(){
+            let num:i64=54;
+            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
+            let cantidad=eleccion.get_cantidad_de_votantes();
+            assert_eq!(cantidad,0);
+        }
+        #[ink::test]
+        fn test_elecciones_cantidad_de_votos_emitidos(){
+            let num:i64=54;
+            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
+            let cantidad=eleccion.get_cantidad_de_votos_emitidos();
+            assert_eq!(cantidad,0);
+        }
+        #[ink::test]
+        fn test_elecciones_candidato(){
+            let num:i64=89;
+            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
+            let candidato=eleccion.get_candidatos();
+            assert_eq!(candidato.len(),0);
+        }
 
-            let dias_totales = dias_desde_1970
-                .checked_add(dias_hasta_mes)
-                .and_then(|v| v.checked_add(dia.checked_sub(1).ok_or("Underflow in dia").ok()?))
-                .ok_or("Overflow in dias_totales")?;
+        #[allow(unused_must_use)]
+        #[test]
+        fn testget_postulados_a_votantes() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.registrar_usuario(String::from("Juan"), String::from("Perez"), String::from("12345678"));
+            sistema.crear_eleccion(String::from("Presidente"),1, 1, 2024,10, 1, 2024,);
+            sistema.postulacion_de_usuario(1, 1, true, None);
+            let eleccion = sistema.obtener_eleccion(1).unwrap();
+            let postulados = eleccion.get_postulados_a_votantes();
+            assert_eq!(postulados.len(), 1);
+            assert_eq!(postulados[0].dato.nombre, String::from("Juan"));
+            assert_eq!(postulados[0].dato.apellido, String::from("Perez"));
+            assert_eq!(postulados[0].dato.dni, String::from("12345678"));
+        }
+        #[ink::test]
+        fn test_aprobar_reporte_ok() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.bob);
 
-            let milisegundos_totales = (dias_totales as i64)
-                .checked_mul(24 * 3600 * 1000) // 24 hours * 3600 seconds * 1000 milliseconds
-                .and_then(|v| v.checked_add((hora as i64).checked_mul(3600 * 1000).ok_or("Overflow in hora").ok()?))
-                .and_then(|v| v.checked_add((minuto as i64).checked_mul(60 * 1000).ok_or("Overflow in minuto").ok()?))
-                .and_then(|v| v.checked_add((segundo as i64).checked_mul(1000).ok_or("Overflow in segundo").ok()?))
-                .and_then(|v| v.checked_add((milisegundos as i64).checked_mul(1).ok_or("Overflow in milisegundos").ok()?))
-                .ok_or("Overflow in milisegundos_totales")?;
-                
-            Ok(milisegundos_totales)
+            let result = sistema.aprobar_reporte(1);
+            
+
+            assert!(result.is_ok());
+            assert!(sistema.esta_reporte_aprobado(accounts.bob));
         }
 
-        //METODOS DE USUARIO
-        
-        ///Crea un nuevo usuario.
-        /// EJEMPLO
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.registrar_usuario(String::from("nombre"),String::from("apellido"),String::from("dni"));
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn registrar_usuario(&mut self, nombre:String, apellido:String, dni:String){
-            let usuario = Usuario::new(nombre, apellido, dni,Self::env().caller() , self.elecciones.len() as i16);
-            self.usuarios_registrados.push(usuario);
+        #[ink::test]
+        fn test_aprobar_reporte_invalido() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.bob);
+
+            let result = sistema.aprobar_reporte(2); 
+
+            assert!(result.is_err());
+            assert!(!sistema.esta_reporte_aprobado(accounts.bob));
+        }
+
+        #[ink::test]
+        fn test_existe_eleccion_false() {
+            let accounts =  ink::env::test::default_accounts::< ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::< ink::env::DefaultEnvironment>(accounts.alice);
+            let sistema = SistemaDeVotacion::new();
+
+            let result = sistema.existe_eleccion(1);
+
+            assert!(!result);
+        }
+        #[allow(clippy::bool_assert_comparison)]
+        #[ink::test]
+        fn test_existe_usuario_id_cero() {
+            let accounts =  ink::env::test::default_accounts::< ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::< ink::env::DefaultEnvironment>(accounts.alice);
+            let sistema = SistemaDeVotacion::new();
+            assert_eq!(sistema.existe_usuario(0), false, "El ID 0 debería ser inválido.");
+        }
+
+        #[allow(clippy::bool_assert_comparison)]
+        #[ink::test]
+        fn test_existe_eleccion_id_cero() {
+            let sistema = SistemaDeVotacion::new();
+            let resultado = sistema.existe_eleccion(0);
+            assert_eq!(resultado, false);
+        }
+
+        #[allow(unused_must_use, clippy::manual_unwrap_or_default, clippy::needless_borrow, clippy::vec_init_then_push)]
+        #[ink::test]
+        fn test_obtener_reportes_aprobados() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.frank);
+            sistema.agregar_accountid_de_reporte(accounts.django);
+            sistema.aprobar_reporte(1);
+            sistema.aprobar_reporte(1);
+            let reportes_aprobados = sistema.obtener_reportes_aprobados();
+            match reportes_aprobados {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
+            }
+            let reportes_aprobados = match reportes_aprobados{
+                Ok(dato) => dato,
+                Err(_e) => Vec::new(),
+            };
+            let mut vec_normal : Vec<AccountId> = Vec::new();
+            vec_normal.push(accounts.frank);
+            vec_normal.push(accounts.django);
+         
+            assert_eq!(reportes_aprobados, vec_normal);
+        }
+
+        #[allow(unused_must_use)]
+        #[ink::test]
+        fn test_get_reportes_rechazados() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.frank);
+            sistema.agregar_accountid_de_reporte(accounts.django);
+            assert_eq!(sistema.reporte_sin_permiso.len(),2);
+            sistema.rechazar_reporte(1);
+            assert_eq!(sistema.reporte_sin_permiso.len(),1);
         }
-        
-        /// - si es_votante es true lo inscribe como votante, en caso contrario como candidato y ademas cambia a true
-        /// - la participacion del usuario en dicha eleccion para que no pueda inscribirse 2 veces en misma eleccion.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.registrar_usuario(String::from("nombre"),String::from("apellido"),String::from("dni"));
-        /// let r = sistema.postulacion_de_usuario(1,1,true);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn postulacion_de_usuario(&mut self, id_usuario:i16, id_eleccion:i16, es_votante:bool)->Result<(), String> {
-            let id_user = id_usuario.checked_sub(1).unwrap();
-            let id_elec = id_eleccion.checked_sub(1).unwrap();
 
-            if id_usuario as usize > self.usuarios_registrados.len() || id_usuario<0{
-                return Err(String::from("Id de usuario invalido"));
-            }
+        #[ink::test]
+        fn reporte_solicita_acceso_con_su_cuenta() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            assert_eq!(sistema.estado_de_reporte(accounts.frank),EstadoDeReporte::SinPedido);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            assert_eq!(sistema.solicitar_acceso(),Ok(EstadoDeReporte::Pendiente));
+            assert_eq!(sistema.solicitar_acceso(),Ok(EstadoDeReporte::Pendiente));
+            assert_eq!(sistema.reporte_sin_permiso.len(),1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let _ = sistema.solicitar_acceso();
 
-            if Self::env().caller() !=self.usuarios_registrados[id_user as usize].datos.accountid{
-                return Err(String::from("No es el usuario quien intenta votar "));
-            }
-            
-            if !self.existe_eleccion(id_eleccion){
-                return Err(String::from("No Existe la eleccion "));
-            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.aprobar_reporte(1),Ok(()));
+            assert_eq!(sistema.rechazar_reporte(1),Ok(()));
+            assert_eq!(sistema.estado_de_reporte(accounts.frank),EstadoDeReporte::Aprobado);
+            assert_eq!(sistema.estado_de_reporte(accounts.django),EstadoDeReporte::Rechazado);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            assert_eq!(sistema.solicitar_acceso(),Ok(EstadoDeReporte::Aprobado));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert!(sistema.solicitar_acceso().is_err());
+            assert!(sistema.reporte_sin_permiso.is_empty());
+            sistema.agregar_accountid_de_reporte(accounts.django);
+            sistema.agregar_accountid_de_reporte(accounts.frank);
+            assert!(sistema.reporte_sin_permiso.is_empty());
+            assert_eq!(sistema.estado_de_reporte(accounts.django),EstadoDeReporte::Rechazado);
+        }
 
-            if !self.existe_usuario(id_usuario){
-                return Err(String::from("No existe usuario"));
-            }
-            
-            if Self::env().block_timestamp() >= (self.elecciones[id_elec as usize].inicio as u64){
-                return Err(String::from(" No se puede inscribir despues de la fecha de inicio de la eleccion "));
-            }
-            
-                
-            let eleccion = self.elecciones.get_mut(id_elec as usize);
-            let eleccion = match eleccion{
-                Some(dato) => dato,
-                None => return Err(String::from("No tiene permiso de administrador")),
-            };
-            let usuario = self.usuarios_registrados.get_mut(id_user as usize);
-            let usuario = match usuario{
-                Some(dato) => dato,
-                None => return Err(String::from("No tiene permiso de administrador")),
-            };
+        #[ink::test]
+        fn reporte_sin_aprobar_recibe_el_estado_de_su_pedido() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("CEO de Intel"), 1, 6, 2024, 20, 6, 2024);//elec 1, cerrada
+            sistema.agregar_accountid_de_reporte(accounts.frank);
+            sistema.agregar_accountid_de_reporte(accounts.frank);
+            sistema.agregar_accountid_de_reporte(accounts.django);
+            assert_eq!(sistema.reporte_sin_permiso.len(),2);
+            assert_eq!(sistema.rechazar_reporte(2),Ok(()));
 
-            if usuario.participacion[id_elec as usize]!=false{
-                return Err(String::from(" No se puede inscribir porque ya esta participando "));
-            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            assert_eq!(sistema.obtener_reporte_de_eleccion(1).unwrap_err(),String::from("El pedido de acceso del reporte esta pendiente de aprobacion por el administrador del sistema"));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(sistema.obtener_resumenes_de_elecciones_cerradas(0, 10).unwrap_err(),String::from("El pedido de acceso del reporte fue rechazado por el administrador del sistema"));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(sistema.obtener_reporte_de_eleccion(1).unwrap_err(),String::from("El reporte no pidio acceso al sistema, llame a solicitar_acceso"));
 
-            if es_votante{
-                eleccion.postulados_a_votantes.push(Votante::new(usuario.clone().datos));
-            }else{
-                eleccion.postulados_a_candidatos.push(Candidato::new(usuario.clone().datos));
-            }
-            
-            self.usuarios_registrados[id_user as usize].participacion[id_elec as usize] = true;
-            Ok(())
-            
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.aprobar_reporte(1),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            assert!(sistema.obtener_reporte_de_eleccion(1).is_ok());
         }
 
-        /// - el id_usuario es la posicion del votante en el vector de usuarios registrados en el sistema de votacion.
-        /// - el id_candidato es la posicion del candidato en el vector candidatos adentro de la eleccion.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.votar_a_candidato(1,1,2);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn votar_a_candidato(&mut self, id_usuario:i16, id_eleccion:i16, id_candidato:i16)->Result<(), String> {
-            let eleccion = self.elecciones.get_mut(id_eleccion.checked_sub(1).unwrap() as usize);
-            let eleccion = match eleccion{
-                Some(dato) => dato,
-                None => return Err(String::from("No se enceuntra la eleccion ")),
-            };
-            let dato_votante = self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.clone();
+        #[allow(unused_must_use)]
+        #[ink::test]
+        fn test_get_reportes_rechazados_sin_permisos() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.frank);
+            sistema.agregar_accountid_de_reporte(accounts.django);
+            assert_eq!(sistema.reporte_sin_permiso.len(),2);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.rechazar_reporte(1);
+            assert_eq!(sistema.reporte_sin_permiso.len(),2);
+        }
 
-            if Self::env().caller() !=self.usuarios_registrados[id_usuario.checked_sub(1).unwrap() as usize].datos.accountid {
-                return Err(String::from("No tiene permiso de usuario "));
+        #[allow(unused_must_use, clippy::needless_borrow, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn get_reporte_eleccion_eleccion_abierta(){
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
+            let res = sistema.obtener_reporte_de_eleccion(1);
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
             }
+            assert!(res.is_err())
+        }
 
-            if (eleccion.candidatos.len() as i16) < id_candidato{
-                return Err(String::from("no existe el candidato "));
-            }
-            
-            if (Self::env().block_timestamp() < eleccion.inicio as u64) || (Self::env().block_timestamp() > eleccion.fin as u64) {
-                let block_timestamp = Self::env().block_timestamp();
-                let mut error_message = String::from("Votación fuera de fecha, timestamp del block actual: ");
-                error_message.push_str(&block_timestamp.to_string());
-                return Err(error_message);
-            }
-            
-            
-            for (index, e) in eleccion.votantes.clone().iter().enumerate(){
-                if (*e).dato==dato_votante && !(*e).estado_del_voto{
-                    eleccion.votantes[index].estado_del_voto = true;
-                    eleccion.candidatos[id_candidato.checked_sub(1).unwrap() as usize].cant_votos = eleccion.candidatos[id_candidato.checked_sub(1).unwrap() as usize].cant_votos.checked_add(1).unwrap();
-                    return Ok(());
-                }else if (*e).dato==dato_votante && (*e).estado_del_voto{
-                    return Err(String::from("El votante ya voto"));
-                }
+        #[allow(unused_must_use, clippy::needless_borrow, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn get_reporte_eleccion_reporte_con_permiso(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_900_900_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.charlie);
+            sistema.aprobar_reporte(1);
+            sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let res = sistema.obtener_reporte_de_eleccion(1);
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
             }
-            return Err(String::from("No contiene este votante "));
+            assert!(res.is_ok())
         }
 
-        //METODOS DEL REPORTE
-
-        /// - Devuelve los datos de una eleccion, solo si esta esta cerrada y tiene los permisos necesarios.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let sistema = SistemaDeVotacion::new();
-        /// let r = sistema.obtener_reporte_de_eleccion(3);
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn obtener_reporte_de_eleccion(&self, id_eleccion:i16)->Result<Eleccion, String>{
-
-            let account = Self::env().caller();
-
-            if account != self.admin.accountid && !self.reportes_con_permiso.contains(&account){
-                return Err(String::from("El reporte no tiene permiso para generar el reporte"));
+        #[allow(unused_must_use, clippy::needless_borrow, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn get_reporte_eleccion_reporte_sin_permiso(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_900_900_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.charlie);
+            sistema.rechazar_reporte(1);
+            sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let res = sistema.obtener_reporte_de_eleccion(1);
+            match res {
+                Ok(_) => ink::env::debug_message("SE PUEDE "),
+                Err(ref e) => ink::env::debug_message(&e),
             }
+            assert!(res.is_err())
+        }
 
-            if !self.existe_eleccion(id_eleccion){
-                return Err(String::from("No existe la elecion"));
-            }
+        #[ink::test]
+        fn reporte_de_elecciones_cerradas_solo_trae_las_cerradas(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            sistema.agregar_accountid_de_reporte(accounts.charlie);
+            let _ = sistema.aprobar_reporte(1);
+            let _ = sistema.crear_eleccion(String::from("CEO de Intel"), 1, 6, 2024, 20, 6, 2024);//elec 1, cerrada
+            let _ = sistema.crear_eleccion(String::from("CEO de AMD"), 1, 7, 2024, 20, 7, 2024);//elec 2, abierta
+            let _ = sistema.crear_eleccion(String::from("CEO de ARM"), 1, 5, 2024, 20, 5, 2024);//elec 3, cerrada
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let cerradas = sistema.obtener_resumenes_de_elecciones_cerradas(0, 10).unwrap().get_elementos();
+            let ids:Vec<i16> = cerradas.iter().map(|(id, _)| *id).collect();
+            assert_eq!(ids, vec![1, 3]);
+            assert_eq!(cerradas[1].1.get_cargo(), String::from("CEO de ARM"));
+            let pagina = sistema.obtener_resumenes_de_elecciones_cerradas(0, 1).unwrap();
+            assert_eq!(pagina.get_elementos()[0].0, 1);
+            assert_eq!(pagina.get_siguiente(), Some(1));
+            let pagina = sistema.obtener_resumenes_de_elecciones_cerradas(1, 1).unwrap();
+            assert_eq!(pagina.get_elementos()[0].0, 3);
+            assert_eq!(pagina.get_siguiente(), None);
+            assert!(sistema.obtener_resumenes_de_elecciones_cerradas(0, MAX_TAMANIO_DE_PAGINA + 1).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert!(sistema.obtener_resumenes_de_elecciones_cerradas(0, 10).is_err());
+        }
 
-            let eleccion = self.elecciones.get(id_eleccion.checked_sub(1).unwrap() as usize).unwrap();
+        #[ink::test]
+        fn no_se_elimina_una_eleccion_anterior_a_una_finalizada(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Vocal"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 2
+            let _ = sistema.crear_eleccion(String::from("Tesorero"), 15, 10, 2024, 20, 11, 2024);//elec 3
+            let fin = sistema.elecciones[1].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            assert!(sistema.finalizar_eleccion(2).is_ok());
+            assert_eq!(sistema.eliminar_eleccion(1),Err(String::from("Hay una eleccion finalizada despues de esta, eliminarla cambiaria su id")));
+            assert_eq!(sistema.eliminar_eleccion(2),Err(String::from("La eleccion esta finalizada")));
+            assert_eq!(sistema.eliminar_eleccion(3),Ok(()));
+            assert_eq!(sistema.elecciones[1].get_cargo(),String::from("Presidente"));
+        }
 
-            if Self::env().block_timestamp()<eleccion.fin as u64{
-                return Err(String::from("La eleccion aun no ha cerrado"));
-            }
-            
-            Ok(eleccion.clone())
+        #[ink::test]
+        fn resumen_cerrado_no_cuenta_dos_veces_una_apelacion_aceptada(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrar_usuario(String::from("Luis"), String::from("Perez"),String::from("3345678"));//user 2
+            assert_eq!(sistema.postulacion_de_usuario(2, 1, true, None),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.rechazar_usuario(1, 1, MotivoDeRechazo::DatosIncompletos),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(sistema.apelar_rechazo(1, 1, String::from("Mi dni esta al dia")),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.resolver_apelacion(1, 1, true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_cantidad_de_postulados_a_votantes(),2);
+
+            let fin = sistema.elecciones[0].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            let resumenes = sistema.obtener_resumenes_de_elecciones_cerradas(0, 10).unwrap().get_elementos();
+            assert_eq!(resumenes[0].1.get_postulados_a_votantes(),2);
+            assert_eq!(resumenes[0].1.get_votantes_aprobados(),1);
         }
 
-        /// - Devuelve true si el reporte esta habilitado para acceder al sistema.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.esta_reporte_aprobado(accounts.charlie);
-        /// ```
-        /// 
-        pub fn esta_reporte_aprobado(&self,id:AccountId)->bool{
-            if self.reportes_con_permiso.contains(&id){
-                return true
-            }
+        #[allow(unused_must_use, unused_variables, clippy::zero_prefixed_literal)]
+        #[ink::test]
+        fn probando_errores(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_900_900_000_000);
+            let mut sistema = SistemaDeVotacion::new();
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 01, 2024, 20, 01, 2024);
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.postulacion_de_usuario(1,1,false,None);
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 01, 2024, 20, 23, 2024);
+            assert!(res.is_err());
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 01, 1969, 20, 02, 2024);
+            assert!(res.is_err());
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 03, 2024, 20, 02, 2024);
+            assert!(res.is_err());
+            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 03, 2024, 20, 02, 1969);
+            assert!(res.is_err());
+            let res = sistema.obtener_reporte_de_eleccion(3);
+            assert!(res.is_err());
+            let res = sistema.validar_usuario(1, 1, true);
+            assert!(res.is_err());
+            let res = sistema.validar_usuario(1, 2, true);
+            assert!(res.is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let res = sistema.eliminar_eleccion(1);
+            assert!(res.is_err());
+            let res = sistema.obtener_reportes_aprobados();
+            assert!(res.is_err());
+            let res = sistema.obtener_reporte_de_eleccion(3);
+            assert!(res.is_err());
+            let res = sistema.validar_usuario(1, 1, true);
+            assert!(res.is_err());
+        }
 
-            false
+        #[ink::test]
+        fn postulacion_de_candidato_con_perfil_asigna_numero_de_boleta(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user 2
+            let perfil = PerfilCandidato::new(String::from("Lista Azul"), String::from("Mas becas"), Some(String::from("bafybeigdyrzt")));
+            let res = sistema.postulacion_de_usuario(1,1,false,Some(perfil.clone()));
+            assert!(res.is_ok());
+            let _ = sistema.postulacion_de_usuario(2,1,false,None);
+            assert_eq!(sistema.elecciones[0].postulados_a_candidatos[0].get_numero_de_boleta(),1);
+            assert_eq!(sistema.elecciones[0].postulados_a_candidatos[0].get_perfil(),perfil);
+            assert_eq!(sistema.elecciones[0].postulados_a_candidatos[1].get_numero_de_boleta(),2);
+            assert_eq!(sistema.elecciones[0].postulados_a_candidatos[1].get_perfil(),PerfilCandidato::default());
         }
 
-        /// - Devielve los accountids de los reportes que estan aprobados.
-        /// - EJEMPLO:
-        /// ```
-        /// use sistema_de_votacion::sistema_de_votacion::SistemaDeVotacion;
-        /// let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-        /// ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-        /// let mut sistema = SistemaDeVotacion::new();
-        /// let r = sistema.obtener_reportes_aprobados();
-        /// ```
-        /// 
-        #[ink(message)]
-        pub fn obtener_reportes_aprobados(&self)->Result<Vec<AccountId>,String>{
-            let account = Self::env().caller();
-            if account != self.admin.accountid && !self.reportes_con_permiso.contains(&account){
-                return Err(String::from("No tiene permisos"));
-            }
-            let reporte=self.reportes_con_permiso.clone();
-            Ok(reporte)
+        #[ink::test]
+        fn editar_perfil_de_candidato_antes_y_despues_del_inicio(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let perfil = PerfilCandidato::new(String::from("Lista Verde"), String::from("Transparencia"), None);
+            let res = sistema.editar_perfil_de_candidato(1,1,perfil.clone());
+            assert!(res.is_ok());
+            assert_eq!(sistema.elecciones[0].candidatos[0].get_perfil(),perfil);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let res = sistema.editar_perfil_de_candidato(1,1,PerfilCandidato::default());
+            assert!(res.is_err());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_729_000_000_000);
+            let res = sistema.editar_perfil_de_candidato(1,1,PerfilCandidato::default());
+            assert!(res.is_err());
+            assert_eq!(sistema.elecciones[0].candidatos[0].get_perfil(),perfil);
         }
-        
-    }
 
-    #[cfg(test)]
-    mod tests {
-        
-        use super::*;
-        
+        #[ink::test]
+        fn perfil_de_candidato_demasiado_largo(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            let perfil = PerfilCandidato::new("a".repeat(MAX_LARGO_PARTIDO + 1), String::from("Propuestas"), None);
+            let res = sistema.postulacion_de_usuario(1,1,false,Some(perfil));
+            assert!(res.is_err());
+            assert_eq!(sistema.elecciones[0].postulados_a_candidatos.len(),0);
+        }
 
         #[ink::test]
-        fn ceder_admin_con_permiso_para_hacerlo(){
+        fn candidatos_de_eleccion_solo_lista_aprobados(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.ceder_admin(accounts.bob);
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user 2
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,false,None);
+            let _ = sistema.validar_usuario(2, 1, true);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            let fichas = sistema.candidatos_de_eleccion(1).unwrap();
+            assert_eq!(fichas.len(),1);
+            assert_eq!(fichas[0].get_nombre(),String::from("Pablo"));
+            assert_eq!(fichas[0].get_numero_de_boleta(),2);
+            assert!(sistema.candidatos_de_eleccion(2).is_err());
+        }
+
+        #[ink::test]
+        fn crear_lista_con_candidatos_aprobados(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Consejo"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user 2
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user 3
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,false,None);
+            let _ = sistema.postulacion_de_usuario(3,1,false,None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            let res = sistema.crear_lista(1, String::from("Lista Azul"), vec![2,1]);
             assert!(res.is_ok());
-            assert_eq!(sistema.admin.accountid, accounts.bob);
+            let lista = &sistema.elecciones[0].listas[0];
+            assert_eq!(lista.get_lider(),Some(2));
+            let candidatos = sistema.elecciones[0].get_candidatos_de_lista(lista);
+            assert_eq!(candidatos[0].dato.nombre,String::from("Pablo"));
+            assert_eq!(candidatos[1].dato.nombre,String::from("Carlos"));
         }
 
         #[ink::test]
-        fn intentar_ceder_admin_sin_permisos(){
+        fn crear_lista_invalida(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Consejo"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user 2
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,false,None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            //el candidato 2 no fue aprobado
+            assert!(sistema.crear_lista(1, String::from("Lista Azul"), vec![1,2]).is_err());
+            assert!(sistema.crear_lista(1, String::from("Lista Azul"), Vec::new()).is_err());
+            assert!(sistema.crear_lista(1, String::from("Lista Azul"), vec![1,1]).is_err());
+            assert!(sistema.crear_lista(1, String::from("Lista Azul"), vec![1]).is_ok());
+            //el candidato 1 ya esta en otra lista
+            assert!(sistema.crear_lista(1, String::from("Lista Roja"), vec![1]).is_err());
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            let res = sistema.ceder_admin(accounts.charlie);
-            assert!(res.is_err());
-            assert_eq!(sistema.admin.accountid,accounts.alice);
+            assert!(sistema.crear_lista(1, String::from("Lista Verde"), vec![1]).is_err());
+            assert_eq!(sistema.elecciones[0].listas.len(),1);
         }
 
         #[ink::test]
-        fn crear_eleccion_admin_invalido() {
+        fn votar_a_lista_y_no_votar_dos_veces(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 3, 2024, 20, 3, 2024);
-            match res {
-                Ok(()) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            assert!(matches!(res, Err(ref e) if e == "No tienes permiso para crear una elección"));
+            let _ = sistema.crear_eleccion(String::from("Consejo"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,true,None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            let _ = sistema.crear_lista(1, String::from("Lista Azul"), vec![1]);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_730_000_000_000);
+            assert!(sistema.votar_a_lista(2, 1, 2, Hash::default()).is_err());
+            let res = sistema.votar_a_lista(2, 1, 1, Hash::default());
+            assert!(res.is_ok());
+            assert_eq!(sistema.elecciones[0].listas[0].get_cantidad_votos(),1);
+            assert_eq!(sistema.elecciones[0].get_cantidad_de_votos_emitidos(),1);
+            let res = sistema.votar_a_candidato(2, 1, 1, Hash::default());
+            assert!(matches!(res, Err(ref e) if e == "El votante ya voto"));
+            assert_eq!(sistema.elecciones[0].candidatos[0].get_cantidad_votos(),0);
         }
-        
+
         #[ink::test]
-        fn instanciar_sistema_de_votacion_y_probar_valores_iniciales(){
-            let sistema = SistemaDeVotacion::new();
-            //Prueba el AccountId guardado con uno capturado del ambiente (entiendo que deberia ser el mismo)
-            assert_eq!(sistema.admin.accountid, ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice);
-            assert_eq!(sistema.elecciones.len(),0);
-            assert_eq!(sistema.usuarios_registrados.len(),0);
+        fn votos_por_intervalo_sin_identificar_votantes(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Consejo"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            assert_eq!(sistema.elecciones[0].get_intervalo_de_votos(),3_600_000);
+            assert!(sistema.configurar_intervalo_de_votos(1, 30_000).is_err());
+            assert_eq!(sistema.configurar_intervalo_de_votos(1, 1_800_000),Ok(()));
+            let votantes = [(accounts.bob, "Ana", "1245623"), (accounts.charlie, "Luis", "3345678"), (accounts.django, "Eva", "4456789")];
+            for (i, (cuenta, nombre, dni)) in votantes.iter().enumerate(){
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(*cuenta);
+                sistema.registrar_usuario(String::from(*nombre), String::from("Perez"),String::from(*dni));
+                let _ = sistema.postulacion_de_usuario(i as i16 + 1,1,true,None);
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            for id in 1..=3{
+                let _ = sistema.validar_usuario(id, 1, true);
+            }
+            for (i, minutos) in [10u64, 20, 50].into_iter().enumerate(){
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(votantes[i].0);
+                ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio + minutos * 60_000);
+                assert!(sistema.votar_en_blanco(i as i16 + 1, 1, Hash::default()).is_ok());
+            }
+            assert_eq!(sistema.elecciones[0].get_votos_por_intervalo(),vec![(inicio, 2), (inicio + 1_800_000, 1)]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(sistema.configurar_intervalo_de_votos(1, 600_000).is_err());
         }
+
         #[ink::test]
-        fn instanciar_sistema_de_votacion_y_probar_valores_iniciales_otro_account_de_admin(){
-            let sistema = SistemaDeVotacion::new();
-            //Prueba el AccountId guardado con uno capturado del ambiente (entiendo que No deberia ser el mismo)
-            assert_ne!(sistema.admin.accountid, ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().bob);
-            assert_eq!(sistema.elecciones.len(),0);
-            assert_eq!(sistema.usuarios_registrados.len(),0);
+        fn postulacion_a_cargos_de_la_misma_eleccion(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            assert!(sistema.agregar_cargo(1, String::from("Tesorero")).is_ok());
+            assert!(sistema.agregar_cargo(1, String::from("Tesorero")).is_err());
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user 2
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user 3
+            assert!(sistema.postulacion_a_cargo(1,1,2,None).is_ok());
+            assert!(sistema.postulacion_a_cargo(2,1,3,None).is_err());
+            assert!(sistema.postulacion_de_usuario(2,1,false,None).is_ok());
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            assert_eq!(sistema.elecciones[0].get_cargos().len(),2);
+            assert_eq!(sistema.elecciones[0].get_candidatos_de_cargo(1)[0].dato.nombre,String::from("Pablo"));
+            assert_eq!(sistema.elecciones[0].get_candidatos_de_cargo(2)[0].dato.nombre,String::from("Carlos"));
         }
 
         #[ink::test]
-        fn crear_eleccion_valida(){
-            let mut sistema = SistemaDeVotacion::new();            
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 01, 2024, 20, 02, 2024);
-            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 03, 2024, 20, 04, 2024);
+        fn votar_boleta_con_un_candidato_por_cargo(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let _ = sistema.agregar_cargo(1, String::from("Tesorero"));
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user 2
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 3
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);//boleta 1, presidente
+            let _ = sistema.postulacion_a_cargo(2,1,2,None);//boleta 2, tesorero
+            let _ = sistema.postulacion_de_usuario(3,1,true,None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            let _ = sistema.validar_usuario(3, 1, true);
+            assert!(sistema.crear_lista(1, String::from("Lista Azul"), vec![1,2]).is_ok());
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_730_000_000_000);
+            assert!(sistema.votar_a_candidato(3, 1, 1, Hash::default()).is_err());
+            assert!(sistema.votar_boleta(3, 1, vec![1], Hash::default()).is_err());
+            assert!(sistema.votar_boleta(3, 1, vec![2,1], Hash::default()).is_err());
+            let res = sistema.votar_a_lista(3, 1, 1, Hash::default());
+            assert!(matches!(res, Err(ref e) if e == "La eleccion tiene varios cargos, se debe votar con votar_boleta"));
+            assert_eq!(sistema.elecciones[0].listas[0].get_cantidad_votos(),0);
+            let res = sistema.votar_boleta(3, 1, vec![1,2], Hash::default());
             assert!(res.is_ok());
-            assert_eq!(sistema.elecciones.len(),2);
+            assert_eq!(sistema.elecciones[0].candidatos[0].get_cantidad_votos(),1);
+            assert_eq!(sistema.elecciones[0].candidatos[1].get_cantidad_votos(),1);
+            assert_eq!(sistema.elecciones[0].get_cantidad_de_votos_emitidos(),1);
+            assert!(sistema.votar_boleta(3, 1, vec![1,2], Hash::default()).is_err());
         }
+
         #[ink::test]
-        fn eliminar_eleccion_valida(){
-            let mut sistema = SistemaDeVotacion::new();            
-            let _ = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 01, 2024, 20, 02, 2024);
-            let _ = sistema.crear_eleccion(String::from("CEO de X"), 15, 03, 2024, 20, 04, 2024);
-            let _ = sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
-            let _ = sistema.postulacion_de_usuario(1,1,false);
-            let _ = sistema.postulacion_de_usuario(1,2,false);
-            let res = sistema.eliminar_eleccion(2);
+        fn crear_referendum_valido_e_invalido(){
+            let mut sistema = SistemaDeVotacion::new();
+            let si_no = vec![String::from("Sí"), String::from("No")];
+            let res = sistema.crear_referendum(String::from("¿Reforma?"), si_no.clone(), ReglaDeMayoria::Simple, 15, 10, 2024, 20, 11, 2024);
             assert!(res.is_ok());
-            assert_eq!(sistema.elecciones.len(),1);
-            assert_eq!(sistema.usuarios_registrados[0].participacion.len(),1);
-        }
-        #[ink::test]
-        fn eliminar_eleccion_invalida(){
-            let mut sistema = SistemaDeVotacion::new();            
-            sistema.crear_eleccion(String::from("CEO de Intel"), 15, 01, 2024, 20, 02, 2024);
-            sistema.crear_eleccion(String::from("CEO de X"), 15, 03, 2024, 20, 04, 2024);
-            let res = sistema.eliminar_eleccion(5);
+            assert_eq!(sistema.elecciones[0].get_tipo(),TipoDeEleccion::Referendum);
+            assert_eq!(sistema.elecciones[0].get_opciones().len(),2);
+            let res = sistema.crear_referendum(String::from("¿Reforma?"), vec![String::from("Sí")], ReglaDeMayoria::Simple, 15, 10, 2024, 20, 11, 2024);
             assert!(res.is_err());
-            assert_eq!(sistema.elecciones.len(),2);
-        }
-
-        #[ink::test]
-        fn rompe_crear_eleccion_timestamp_invalido(){
-            let mut sistema = SistemaDeVotacion::new();            
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 13, 19, 20, 14, 2024);
-            match res {
-                Ok(()) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            };
+            let res = sistema.crear_referendum(String::from("¿Reforma?"), vec![String::from("Sí"), String::from("Sí")], ReglaDeMayoria::Simple, 15, 10, 2024, 20, 11, 2024);
+            assert!(res.is_err());
+            let res = sistema.crear_referendum(String::from("¿Reforma?"), si_no, ReglaDeMayoria::Calificada(10_001), 15, 10, 2024, 20, 11, 2024);
             assert!(res.is_err());
+            assert_eq!(sistema.elecciones.len(),1);
         }
 
         #[ink::test]
-        fn crear_eleccion_fecha_invalida(){
+        fn votar_opcion_en_referendum(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 05, 2024, 20, 03, 2024);
-            assert!(matches!(res,Err(e) if e == "La fecha de inicio debe ser anterior a la fecha de fin"))
+            let _ = sistema.crear_referendum(String::from("¿Reforma?"), vec![String::from("Sí"), String::from("No")], ReglaDeMayoria::Absoluta, 15, 10, 2024, 20, 11, 2024);
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            assert!(sistema.postulacion_de_usuario(1,1,false,None).is_err());
+            let _ = sistema.postulacion_de_usuario(2,1,true,None);
+            let _ = sistema.validar_usuario(2, 1, true);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_730_000_000_000);
+            assert!(sistema.votar_opcion(2, 1, 3, Hash::default()).is_err());
+            assert!(sistema.votar_opcion(2, 1, 1, Hash::default()).is_ok());
+            assert!(sistema.votar_opcion(2, 1, 2, Hash::default()).is_err());
+            assert_eq!(sistema.elecciones[0].opciones[0].get_cantidad_votos(),1);
+            assert_eq!(sistema.elecciones[0].get_cantidad_de_votos_emitidos(),1);
+            assert_eq!(sistema.elecciones[0].get_opcion_ganadora(),Some(1));
         }
 
         #[ink::test]
-        fn crear_usuarios(){
-            let mut sistema = SistemaDeVotacion::new();
-            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));
-            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));
-            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));
-            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));
-            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));
-            assert_eq!(sistema.usuarios_registrados.len(),5);
+        fn opcion_ganadora_segun_regla_de_mayoria(){
+            let num:i64=54;
+            let mut eleccion = Eleccion::new(String::from("¿Reforma?"),&num,&num);
+            eleccion.tipo = TipoDeEleccion::Referendum;
+            eleccion.opciones = vec![Opcion::new(String::from("Sí")), Opcion::new(String::from("No")), Opcion::new(String::from("Abstención"))];
+            assert_eq!(eleccion.get_opcion_ganadora(),None);
+            eleccion.opciones[0].cant_votos = 45;
+            eleccion.opciones[1].cant_votos = 35;
+            eleccion.opciones[2].cant_votos = 20;
+            assert_eq!(eleccion.get_opcion_ganadora(),Some(1));
+            eleccion.regla_de_mayoria = ReglaDeMayoria::Absoluta;
+            assert_eq!(eleccion.get_opcion_ganadora(),None);
+            eleccion.opciones[0].cant_votos = 51;
+            eleccion.opciones[1].cant_votos = 29;
+            assert_eq!(eleccion.get_opcion_ganadora(),Some(1));
+            eleccion.regla_de_mayoria = ReglaDeMayoria::Calificada(6667);
+            assert_eq!(eleccion.get_opcion_ganadora(),None);
+            eleccion.opciones[1].cant_votos = 35;
+            eleccion.opciones[0].cant_votos = 35;
+            eleccion.regla_de_mayoria = ReglaDeMayoria::Simple;
+            assert_eq!(eleccion.get_opcion_ganadora(),None);
         }
 
         #[ink::test]
-        fn probando_acceso_con_getter_a_eleccion_en_la_posicion_deseada(){
+        fn obtener_usuarios_paginado(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 05, 2024, 20, 06, 2024);
-            let res = sistema.crear_eleccion(String::from("CEO de X"), 15, 07, 2024, 20, 08, 2024);
             sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));
             sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));
             sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));
-            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));
-            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));
-            let aux = sistema.obtener_eleccion(2);
-            assert_eq!(aux.unwrap().cargo,sistema.elecciones[1].cargo);
+            let pagina = sistema.obtener_usuarios_paginado(0, 2).unwrap();
+            assert_eq!(pagina.get_elementos().len(),2);
+            assert_eq!(pagina.get_siguiente(),Some(2));
+            let pagina = sistema.obtener_usuarios_paginado(2, 2).unwrap();
+            assert_eq!(pagina.get_elementos()[0].0,3);
+            assert_eq!(pagina.get_elementos()[0].1.datos.nombre,String::from("Jose"));
+            assert_eq!(pagina.get_siguiente(),None);
+            assert!(sistema.obtener_usuarios_paginado(0, 0).is_err());
+            assert!(sistema.obtener_usuarios_paginado(0, MAX_TAMANIO_DE_PAGINA + 1).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(sistema.obtener_usuarios_paginado(0, 2).is_err());
+            assert_eq!(sistema.obtener_usuarios_registrados().unwrap_err(),String::from("No tiene permiso de administrador"));
+            assert_eq!(sistema.obtener_todas_las_elecciones().unwrap_err(),String::from("No tiene permiso de administrador"));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.obtener_usuarios_registrados().unwrap().len(),3);
+            assert!(sistema.obtener_todas_las_elecciones().unwrap().is_empty());
         }
 
         #[ink::test]
-        fn postulacion_de_usuario(){
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_720_210_000_000);
+        fn obtener_elecciones_paginado_con_filtros(){
             let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 10, 2024, 20, 11, 2024);//elec 1
-            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
-            sistema.postulacion_de_usuario(1,1,false);
-            assert_eq!(sistema.elecciones[0].postulados_a_candidatos[0].dato.nombre,String::from("Carlos"));
-        }
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 1, 7, 2024, 20, 7, 2024);//elec 1
+            let _ = sistema.crear_eleccion(String::from("Tesorero"), 15, 10, 2024, 20, 11, 2024);//elec 2
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2025, 20, 11, 2025);//elec 3
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_730_000_000_000);
 
-        #[ink::test]
-        fn vallidacion_de_usuario(){
-            let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 11, 2024, 20, 12, 2024);//elec 1
-            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
-            sistema.postulacion_de_usuario(1,1,false);
-            sistema.validar_usuario(1, 1, true);
-            assert_eq!(sistema.elecciones[0].candidatos[0].dato.nombre,String::from("Carlos"));
-        }
+            let filtro = FiltroDeElecciones::new(Some(EstadoDeEleccion::Abierta), None, None, None);
+            let pagina = sistema.obtener_elecciones_paginado(0, 10, filtro).unwrap();
+            assert_eq!(pagina.get_elementos().len(),1);
+            assert_eq!(pagina.get_elementos()[0].0,2);
 
-        #[ink::test]
-        fn probar_votar(){
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000);
-            let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
-            let res = sistema.crear_eleccion(String::from("CEO de X"), 2, 03, 2024, 20, 07, 2024);//elec 2
-            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
-            sistema.postulacion_de_usuario(1,1,false);
-            sistema.postulacion_de_usuario(1,2,true);
-            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user2
-            sistema.postulacion_de_usuario(2,1,false);
-            sistema.postulacion_de_usuario(2,2,true);
-            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user3
-            sistema.postulacion_de_usuario(3,1,true);
-            sistema.postulacion_de_usuario(3,2,true);
-            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user4
-            sistema.postulacion_de_usuario(4,1,true);
-            sistema.postulacion_de_usuario(4,2,false);
-            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));//user5
-            sistema.postulacion_de_usuario(5,1,true);
-            sistema.postulacion_de_usuario(5,2,false);
-            sistema.validar_usuario(1, 1, true);
-            sistema.validar_usuario(1, 2, true);
-            sistema.validar_usuario(2, 1, true);
-            sistema.validar_usuario(2, 2, true);
-            sistema.validar_usuario(3, 1, true);
-            sistema.validar_usuario(3, 2, true);
-            sistema.validar_usuario(4, 1, true);
-            sistema.validar_usuario(4, 2, true);
-            sistema.validar_usuario(5, 1, true);
-            sistema.validar_usuario(5, 2, true);
-            let user = sistema.obtener_usuario(1);
-            let usuarios_registrados = sistema.obtener_usuarios_registrados();
-            let todas_las_elecciones = sistema.obtener_todas_las_elecciones();
-            let reporte_de_eleccion = sistema.obtener_reporte_de_eleccion(1);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
-            let res = sistema.votar_a_candidato(3, 1, 2);
-            let res = sistema.votar_a_candidato(3, 1, 2);
-            let res = sistema.votar_a_candidato(1, 1, 2);
-            match res {
-                Ok(()) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            let res = sistema.votar_a_candidato(4, 1, 2);
-            let res = sistema.votar_a_candidato(5, 1, 2);
-            let cant = sistema.elecciones[0].get_cantidad_de_votos_emitidos();
-            assert_eq!(cant,3);
-            let cant_votos = sistema.elecciones[0].candidatos[0].get_cantidad_votos();
-                
-            assert_eq!(sistema.elecciones[0].candidatos[1].cant_votos,3);
-            assert_eq!(user.unwrap().datos.nombre,String::from("Carlos"));
-            assert_eq!(usuarios_registrados.len(),5);
-            assert_eq!(todas_las_elecciones.len(),2);
-            assert!(reporte_de_eleccion.is_err());
+            let filtro = FiltroDeElecciones::new(None, None, None, Some(String::from("Presidente")));
+            let pagina = sistema.obtener_elecciones_paginado(0, 1, filtro.clone()).unwrap();
+            assert_eq!(pagina.get_elementos()[0].0,1);
+            let pagina = sistema.obtener_elecciones_paginado(pagina.get_siguiente().unwrap(), 1, filtro).unwrap();
+            assert_eq!(pagina.get_elementos()[0].0,3);
+
+            let desde = SistemaDeVotacion::timestamp(2024,10,1,0,0,0,0).unwrap();
+            let hasta = SistemaDeVotacion::timestamp(2024,12,31,0,0,0,0).unwrap();
+            let filtro = FiltroDeElecciones::new(None, Some(desde), Some(hasta), None);
+            let pagina = sistema.obtener_elecciones_paginado(0, 10, filtro).unwrap();
+            assert_eq!(pagina.get_elementos().len(),1);
+            assert_eq!(pagina.get_elementos()[0].1.get_cargo(),String::from("Tesorero"));
         }
 
         #[ink::test]
-        fn verificar_que_un_usuario_no_se_puede_postular_a_ambos_roles_en_una_eleccion(){
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
+        fn catalogo_publico_de_elecciones(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let _ = sistema.crear_referendum(String::from("¿Reforma?"), vec![String::from("Sí"), String::from("No")], ReglaDeMayoria::Simple, 15, 10, 2024, 20, 11, 2024);//elec 2
             sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
-            sistema.postulacion_de_usuario(1,1,false);
-            let r = sistema.postulacion_de_usuario(1,1,true);
-            match res {
-                Ok(()) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            assert!(r.is_err());
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user 3
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,true,None);
+            let _ = sistema.postulacion_de_usuario(3,1,true,None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            let pagina = sistema.catalogo_de_elecciones(0, 10).unwrap();
+            assert_eq!(pagina.get_elementos().len(),2);
+            let vista = &pagina.get_elementos()[0].1;
+            assert_eq!(vista.get_estado(),EstadoDeEleccion::NoIniciada);
+            assert_eq!(vista.get_candidatos()[0].get_nombre(),String::from("Carlos"));
+            assert_eq!(vista.get_cantidad_postulados_a_votantes(),1);
+            assert_eq!(vista.get_cantidad_votantes_aprobados(),1);
+            let vista = sistema.vista_de_eleccion(2).unwrap();
+            assert_eq!(vista.get_tipo(),TipoDeEleccion::Referendum);
+            assert_eq!(vista.get_opciones(),vec![String::from("Sí"), String::from("No")]);
+            assert!(sistema.vista_de_eleccion(3).is_err());
         }
 
         #[ink::test]
-        fn probar_votar_fuera_de_fecha(){
+        fn mi_estado_en_varias_elecciones(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 15, 12, 2024, 20, 12, 2024);//elec 1
-            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
-            sistema.postulacion_de_usuario(1,1,false);
-            sistema.registrar_usuario(String::from("Pablo"), String::from("Gonzales"),String::from("1234567"));//user2
-            sistema.postulacion_de_usuario(2,1,false);
-            sistema.postulacion_de_usuario(2,2,true);
-            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user3
-            sistema.postulacion_de_usuario(3,1,true);
-            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user4
-            sistema.postulacion_de_usuario(4,1,true);
-            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));//user5
-            sistema.postulacion_de_usuario(5,1,true);
-            sistema.validar_usuario(1, 1, true);
-            sistema.validar_usuario(2, 1, true);
-            sistema.validar_usuario(3, 1, true);
-            sistema.validar_usuario(4, 1, true);
-            sistema.validar_usuario(5, 1, true);
-            let timestamp_inicial = SistemaDeVotacion::timestamp(2024,12,15,0,0,0,0);
-            let timestamp_inicial = match timestamp_inicial {
-                Ok(dato) => dato,
-                _ => -1,
-            };
-            ink::env::debug_message(&format!("timestamp inicial: {}    ", timestamp_inicial));
-            let timestamp_final = SistemaDeVotacion::timestamp(2024,12,20,0,0,0,0);
-            let timestamp_final = match timestamp_final {
-                Ok(dato) => dato,
-                _ => -1,
-            };
-            ink::env::debug_message(&format!("timestamp final: {}    ", timestamp_final));
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_734_000_000_000);
-            let timeblock = ink::env::block_timestamp::<ink::env::DefaultEnvironment>();
-            ink::env::debug_message(&format!("Current block timestamp: {}   ", timeblock));
-            let res = sistema.votar_a_candidato(3, 1, 2);
-            match res {
-                Ok(()) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            assert!(res.is_err()); 
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let _ = sistema.crear_eleccion(String::from("Tesorero"), 15, 10, 2024, 20, 11, 2024);//elec 2
+            let _ = sistema.crear_eleccion(String::from("Secretario"), 15, 10, 2024, 20, 11, 2024);//elec 3
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 1
+            let _ = sistema.postulacion_de_usuario(1,1,true,None);
+            let _ = sistema.postulacion_de_usuario(1,2,false,None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 2
+            let _ = sistema.postulacion_de_usuario(2,1,false,None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(!sistema.mi_estado()[0].get_voto_emitido());
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_730_000_000_000);
+            let _ = sistema.votar_a_candidato(1, 1, 1, Hash::default());
+            let estados = sistema.mi_estado();
+            assert_eq!(estados.len(),2);
+            assert_eq!(estados[0].get_id_eleccion(),1);
+            assert_eq!(estados[0].get_rol(),Rol::Votante);
+            assert_eq!(estados[0].get_validacion(),EstadoDeValidacion::Aprobado);
+            assert!(estados[0].get_voto_emitido());
+            assert_eq!(estados[1].get_id_eleccion(),2);
+            assert_eq!(estados[1].get_cargo(),String::from("Tesorero"));
+            assert_eq!(estados[1].get_rol(),Rol::Candidato);
+            assert_eq!(estados[1].get_validacion(),EstadoDeValidacion::Postulado);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(sistema.mi_estado().len(),0);
+        }
+
+        #[ink::test]
+        fn rechazar_usuario_con_motivo(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,true,None);
+            assert!(sistema.rechazar_usuario(1, 1, MotivoDeRechazo::DatosIncompletos).is_ok());
+            assert!(sistema.validar_usuario(2, 1, false).is_ok());
+            assert_eq!(sistema.elecciones[0].postulados_a_candidatos.len(),0);
+            assert_eq!(sistema.elecciones[0].postulados_a_votantes.len(),0);
+            let rechazados = sistema.elecciones[0].get_rechazados();
+            assert_eq!(rechazados[0].get_rol(),Rol::Candidato);
+            assert_eq!(rechazados[0].get_motivo(),MotivoDeRechazo::DatosIncompletos);
+            assert_eq!(rechazados[1].get_rol(),Rol::Votante);
+            assert_eq!(rechazados[1].get_motivo(),MotivoDeRechazo::SinEspecificar);
+            //ya no esta pendiente
+            assert!(sistema.rechazar_usuario(1, 1, MotivoDeRechazo::Otro).is_err());
+            assert_eq!(sistema.mi_estado()[0].get_validacion(),EstadoDeValidacion::Rechazado);
         }
 
         #[ink::test]
-        fn test_elecciones_postulados_votantes(){
-            let num:i64=54;
-            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
-            let votantes=eleccion.get_postulados_a_votantes();
-            assert_eq!(votantes.len(),0);
+        fn apelar_rechazo_y_resolver(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 1
+            let _ = sistema.postulacion_de_usuario(1,1,true,None);
+            assert!(sistema.apelar_rechazo(1, 1, String::from("No fui rechazada")).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let _ = sistema.rechazar_usuario(1, 1, MotivoDeRechazo::NoHabilitado);
+            //sin apelacion no hay nada que resolver
+            assert!(sistema.resolver_apelacion(1, 1, true).is_err());
+            //solo el usuario puede apelar
+            assert!(sistema.apelar_rechazo(1, 1, String::from("Estoy habilitada")).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(sistema.apelar_rechazo(1, 1, String::from("Estoy habilitada")).is_ok());
+            assert!(sistema.apelar_rechazo(1, 1, String::from("Otra vez")).is_err());
+            assert!(sistema.resolver_apelacion(1, 1, true).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert!(sistema.resolver_apelacion(1, 1, true).is_ok());
+            let rechazo = &sistema.elecciones[0].get_rechazados()[0];
+            assert_eq!(rechazo.get_apelacion(),EstadoDeApelacion::Aceptada);
+            assert_eq!(rechazo.get_descargo(),String::from("Estoy habilitada"));
+            assert_eq!(sistema.elecciones[0].votantes.len(),1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(sistema.mi_estado()[0].get_validacion(),EstadoDeValidacion::Aprobado);
         }
+
         #[ink::test]
-        fn test_elecciones_votantes(){
-            let num:i64=54;
-            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
-            let votantes=eleccion.get_votantes();
-            assert_eq!(votantes.len(),0);
+        fn apelar_rechazo_despues_del_inicio(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 1
+            let _ = sistema.postulacion_de_usuario(1,1,true,None);
+            let _ = sistema.rechazar_usuario(1, 1, MotivoDeRechazo::Duplicado);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_730_000_000_000);
+            assert!(sistema.apelar_rechazo(1, 1, String::from("No es un duplicado")).is_err());
+            assert_eq!(sistema.elecciones[0].get_rechazados()[0].get_apelacion(),EstadoDeApelacion::SinApelar);
         }
+
         #[ink::test]
-        fn test_elecciones_cantidad_de_votantes(){
-            let num:i64=54;
-            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
-            let cantidad=eleccion.get_cantidad_de_votantes();
-            assert_eq!(cantidad,0);
+        fn validar_usuarios_por_lote_no_atomico(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user 3
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,true,None);
+            let resultados = sistema.validar_usuarios(1, vec![(1,true),(3,true),(2,false)], false).unwrap();
+            assert!(resultados[0].is_ok());
+            assert!(resultados[1].is_err());
+            assert!(resultados[2].is_ok());
+            assert_eq!(sistema.elecciones[0].candidatos.len(),1);
+            assert_eq!(sistema.elecciones[0].get_rechazados().len(),1);
         }
+
         #[ink::test]
-        fn test_elecciones_cantidad_de_votos_emitidos(){
-            let num:i64=54;
-            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
-            let cantidad=eleccion.get_cantidad_de_votos_emitidos();
-            assert_eq!(cantidad,0);
+        fn validar_usuarios_por_lote_atomico(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,true,None);
+            let res = sistema.validar_usuarios(1, vec![(1,true),(1,false)], true);
+            assert!(matches!(res, Err(ref e) if e == "Decision 2: El usuario esta repetido en el lote"));
+            let res = sistema.validar_usuarios(1, vec![(1,true),(3,true)], true);
+            assert!(res.is_err());
+            assert_eq!(sistema.elecciones[0].candidatos.len(),0);
+            let res = sistema.validar_usuarios(1, vec![(1,true),(2,true)], true);
+            assert!(res.is_ok());
+            assert_eq!(sistema.elecciones[0].candidatos.len(),1);
+            assert_eq!(sistema.elecciones[0].votantes.len(),1);
+            assert!(sistema.validar_usuarios(1, Vec::new(), true).is_err());
         }
+
         #[ink::test]
-        fn test_elecciones_candidato(){
-            let num:i64=89;
-            let eleccion = Eleccion::new("presidente".to_string(),&num,&num);
-            let candidato=eleccion.get_candidatos();
-            assert_eq!(candidato.len(),0);
+        fn validar_pendientes_con_regla(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            sistema.registrar_usuario(String::from("Jose"), String::from("Peres"),String::from("1928492"));//user 3
+            sistema.registrar_usuario(String::from("Maria"), String::from("Leon"),String::from("43554456"));//user 4
+            let _ = sistema.postulacion_de_usuario(1,1,false,None);
+            let _ = sistema.postulacion_de_usuario(2,1,true,None);
+            let _ = sistema.postulacion_de_usuario(3,1,true,None);
+            let _ = sistema.postulacion_de_usuario(4,1,true,None);
+            assert!(sistema.validar_pendientes(1, 10).is_err());
+            let padron = vec![String::from("1245623"), String::from("1928492"), String::from("7654456")];
+            let _ = sistema.configurar_regla_de_validacion(1, ReglaDeValidacion::Padron(padron));
+            assert_eq!(sistema.validar_pendientes(1, 1),Ok(1));
+            assert_eq!(sistema.validar_pendientes(1, 10),Ok(2));
+            assert_eq!(sistema.elecciones[0].votantes.len(),2);
+            assert_eq!(sistema.elecciones[0].candidatos.len(),1);
+            assert_eq!(sistema.elecciones[0].postulados_a_votantes[0].dato.nombre,String::from("Maria"));
+            let _ = sistema.configurar_regla_de_validacion(1, ReglaDeValidacion::Rol(Rol::Candidato));
+            assert_eq!(sistema.validar_pendientes(1, 10),Ok(0));
         }
 
-        #[test]
-        fn testget_postulados_a_votantes() {
+        #[ink::test]
+        fn actualizar_codigo_sin_permiso(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            sistema.registrar_usuario(String::from("Juan"), String::from("Perez"), String::from("12345678"));
-            sistema.crear_eleccion(String::from("Presidente"),1, 1, 2024,10, 1, 2024,);
-            sistema.postulacion_de_usuario(1, 1, true);
-            let eleccion = sistema.obtener_eleccion(1).unwrap();
-            let postulados = eleccion.get_postulados_a_votantes();
-            assert_eq!(postulados.len(), 1);
-            assert_eq!(postulados[0].dato.nombre, String::from("Juan"));
-            assert_eq!(postulados[0].dato.apellido, String::from("Perez"));
-            assert_eq!(postulados[0].dato.dni, String::from("12345678"));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(sistema.actualizar_codigo([0x01; 32].into()).is_err());
+            assert!(sistema.migrar_almacenamiento(10).is_err());
         }
+
         #[ink::test]
-        fn test_aprobar_reporte_ok() {
+        fn migrar_almacenamiento_desde_la_version_1(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            sistema.agregar_accountid_de_reporte(accounts.bob);
+            assert_eq!(sistema.version_de_almacenamiento(),(VERSION_DE_ALMACENAMIENTO,false));
+            assert_eq!(sistema.migrar_almacenamiento(10),Ok(true));
 
-            let result = sistema.aprobar_reporte(1);
-            
+            //guarda la raiz codificada con el formato de la version 1
+            let persona = |nombre:&str, dni:&str, accountid:AccountId| version_1::PersonaV1{nombre:String::from(nombre), apellido:String::from("Perez"), dni:String::from(dni), accountid};
+            let anterior = version_1::SistemaDeVotacionV1{
+                admin:persona("admin", "admin", accounts.alice),
+                reporte_sin_permiso:vec![accounts.eve],
+                reportes_con_permiso:vec![accounts.frank],
+                usuarios_registrados:vec![
+                    version_1::UsuarioV1{datos:persona("Carlos", "7654456", accounts.bob), participacion:vec![true, false]},
+                    version_1::UsuarioV1{datos:persona("Ana", "1245623", accounts.charlie), participacion:vec![true]},
+                ],
+                elecciones:vec![
+                    version_1::EleccionV1{
+                        cargo:String::from("Presidente"), inicio:1728950400000, fin:1732147199000,
+                        postulados_a_votantes:Vec::new(),
+                        votantes:vec![version_1::VotanteV1{dato:persona("Carlos", "7654456", accounts.bob), estado_del_voto:true}],
+                        postulados_a_candidatos:Vec::new(),
+                        candidatos:vec![version_1::CandidatoV1{dato:persona("Ana", "1245623", accounts.charlie), cant_votos:1}],
+                    },
+                    version_1::EleccionV1{cargo:String::from("Tesorero"), inicio:1728950400000, fin:1732147199000, ..Default::default()},
+                ],
+            };
+            let raiz = <SistemaDeVotacion as ink::storage::traits::StorageKey>::KEY;
+            ink::env::set_contract_storage(&raiz, &anterior);
+            assert!(ink::env::get_contract_storage::<_, SistemaDeVotacion>(&raiz).is_err());
 
-            assert!(result.is_ok());
-            assert!(sistema.esta_reporte_aprobado(accounts.bob));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(SistemaDeVotacion::migrar_desde_version_1(),Err(String::from("No tiene permiso de admin")));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(SistemaDeVotacion::migrar_desde_version_1(),Ok(()));
+            assert_eq!(SistemaDeVotacion::migrar_desde_version_1(),Err(String::from("No hay datos guardados con el formato de la version 1")));
+
+            let mut sistema = ink::env::get_contract_storage::<_, SistemaDeVotacion>(&raiz).unwrap().unwrap();
+            assert_eq!(sistema.version_de_almacenamiento(),(1,true));
+            assert!(sistema.migrar_almacenamiento(0).is_err());
+            assert_eq!(sistema.migrar_almacenamiento(3),Ok(false));
+            assert_eq!(sistema.migrar_almacenamiento(3),Ok(true));
+            assert_eq!(sistema.version_de_almacenamiento(),(VERSION_DE_ALMACENAMIENTO,false));
+
+            assert_eq!(sistema.admin.accountid,accounts.alice);
+            assert_eq!(sistema.tesoreria,accounts.alice);
+            assert_eq!(sistema.estado_de_reporte(accounts.eve),EstadoDeReporte::Pendiente);
+            assert_eq!(sistema.estado_de_reporte(accounts.frank),EstadoDeReporte::Aprobado);
+            assert_eq!(sistema.usuarios_registrados[0].participacion,vec![true, false]);
+            assert_eq!(sistema.usuarios_registrados[1].participacion,vec![true, false]);
+            assert_eq!(sistema.elecciones[0].get_cargos(),vec![String::from("Presidente")]);
+            assert_eq!(sistema.elecciones[0].get_candidatos()[0].get_numero_de_boleta(),1);
+            assert_eq!(sistema.elecciones[0].get_candidatos()[0].get_id_cargo(),1);
+            assert_eq!(sistema.elecciones[0].get_candidatos()[0].get_cantidad_votos(),1);
+            assert_eq!(sistema.elecciones[0].proximo_numero_de_boleta,2);
+            assert_eq!(sistema.elecciones[0].get_cantidad_de_votos_emitidos(),1);
+            assert_eq!(sistema.elecciones[1].get_cargos(),vec![String::from("Tesorero")]);
+            assert_eq!((sistema.elecciones[0].clave, sistema.elecciones[1].clave),(1, 2));
+
+            //los datos migrados se siguen usando con el codigo actual
+            assert_eq!(sistema.crear_eleccion(String::from("Vocal"), 15, 10, 2024, 20, 11, 2024),Ok(()));
+            assert_eq!(sistema.elecciones[2].clave,3);
+            assert_eq!(sistema.usuarios_registrados[1].participacion.len(),3);
+            ink::env::set_contract_storage(&raiz, &sistema);
+            assert!(ink::env::get_contract_storage::<_, SistemaDeVotacion>(&raiz).is_ok());
         }
 
         #[ink::test]
-        fn test_aprobar_reporte_invalido() {
+        fn pausar_y_reanudar_eleccion(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             let mut sistema = SistemaDeVotacion::new();
-            sistema.agregar_accountid_de_reporte(accounts.bob);
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(sistema.pausar_eleccion(1, String::from("Error")).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.pausar_eleccion(1, String::from("Error")),Ok(()));
+            assert!(sistema.pausar_eleccion(1, String::from("Otra vez")).is_err());
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None),Err(String::from("La eleccion esta pausada")));
+            let pausa = sistema.elecciones[0].get_pausa().unwrap();
+            assert_eq!(pausa.get_por(),accounts.alice);
+            assert_eq!(pausa.get_motivo(),String::from("Error"));
 
-            let result = sistema.aprobar_reporte(2); 
+            //no habia empezado, no se extiende
+            let fin = sistema.elecciones[0].get_fin();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+            assert_eq!(sistema.reanudar_eleccion(1, true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_fin(),fin);
+            assert!(sistema.reanudar_eleccion(1, true).is_err());
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None),Ok(()));
 
-            assert!(result.is_err());
-            assert!(!sistema.esta_reporte_aprobado(accounts.bob));
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio);
+            assert_eq!(sistema.pausar_eleccion(1, String::from("Error")),Ok(()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+1000);
+            assert_eq!(sistema.reanudar_eleccion(1, true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_fin(),fin+1000);
+            assert_eq!(ink::env::test::recorded_events().count(),7);//creada, registrado, pausada, reanudada, postulado, pausada y reanudada
         }
 
         #[ink::test]
-        fn test_existe_eleccion_false() {
-            let accounts =  ink::env::test::default_accounts::< ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::< ink::env::DefaultEnvironment>(accounts.alice);
-            let sistema = SistemaDeVotacion::new();
-
-            let result = sistema.existe_eleccion(1);
+        fn pausar_sistema_bloquea_todas_las_elecciones(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let _ = sistema.crear_eleccion(String::from("Tesorero"), 15, 10, 2024, 20, 11, 2024);//elec 2
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            let _ = sistema.postulacion_de_usuario(1, 2, true, None);
+            let _ = sistema.validar_usuario(1, 2, true);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(sistema.pausar_sistema(String::from("Error")).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.pausar_sistema(String::from("Error")),Ok(()));
+            assert!(sistema.pausa_del_sistema().is_some());
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None),Err(String::from("El sistema esta pausado")));
+            let inicio = sistema.elecciones[1].get_inicio() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio);
+            assert_eq!(sistema.votar_a_candidato(1, 2, 1, Hash::default()),Err(String::from("El sistema esta pausado")));
 
-            assert!(!result);
-        }
-        #[ink::test]
-        fn test_existe_usuario_id_cero() {
-            let accounts =  ink::env::test::default_accounts::< ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::< ink::env::DefaultEnvironment>(accounts.alice);
-            let sistema = SistemaDeVotacion::new();
-            assert_eq!(sistema.existe_usuario(0), false, "El ID 0 debería ser inválido.");
+            let fin = sistema.elecciones[1].get_fin();
+            assert_eq!(sistema.reanudar_sistema(false),Ok(()));
+            assert_eq!(sistema.elecciones[1].get_fin(),fin);
+            assert!(sistema.pausa_del_sistema().is_none());
+            assert_eq!(sistema.votar_a_candidato(1, 2, 1, Hash::default()),Err(String::from("no existe el candidato ")));
         }
 
         #[ink::test]
-        fn test_existe_eleccion_id_cero() {
-            let sistema = SistemaDeVotacion::new();
-            let resultado = sistema.existe_eleccion(0);
-            assert_eq!(resultado, false);
+        fn reanudar_el_sistema_solo_extiende_las_elecciones_abiertas(){
+            let mut sistema = SistemaDeVotacion::new();
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let _ = sistema.crear_eleccion(String::from("Tesorero"), 15, 12, 2024, 20, 12, 2024);//elec 2
+            let _ = sistema.crear_eleccion(String::from("Vocal"), 15, 10, 2024, 20, 11, 2024);//elec 3
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            let fines:Vec<i64> = sistema.elecciones.iter().map(|e| e.get_fin()).collect();
+            let inicio_de_la_segunda = sistema.elecciones[1].get_inicio();
+            let dia:u64 = 86_400_000;
+
+            //el sistema se pausa un dia antes de que empiecen la primera y la tercera, la tercera ademas se pausa sola
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio-dia);
+            assert_eq!(sistema.pausar_sistema(String::from("Error")),Ok(()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+dia);
+            assert_eq!(sistema.pausar_eleccion(3, String::from("Error")),Ok(()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+2*dia);
+            assert_eq!(sistema.reanudar_sistema(true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_fin(),fines[0]+2*dia as i64);//solo lo que estuvo abierta
+            assert_eq!(sistema.elecciones[1].get_fin(),fines[1]);//no habia empezado
+            assert_eq!(sistema.elecciones[1].get_inicio(),inicio_de_la_segunda);
+            assert_eq!(sistema.elecciones[2].get_fin(),fines[2]+dia as i64);//hasta su propia pausa
+
+            //al reanudarla se extiende desde su propia pausa, sin volver a sumar el dia que ya cubrio el sistema
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+4*dia);
+            assert_eq!(sistema.reanudar_eleccion(3, true),Ok(()));
+            assert_eq!(sistema.elecciones[2].get_fin(),fines[2]+4*dia as i64);
         }
 
         #[ink::test]
-        fn test_obtener_reportes_aprobados() {
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+        fn una_pausa_dentro_de_otra_no_se_extiende_dos_veces(){
             let mut sistema = SistemaDeVotacion::new();
-            sistema.agregar_accountid_de_reporte(accounts.frank);
-            sistema.agregar_accountid_de_reporte(accounts.django);
-            sistema.aprobar_reporte(1);
-            sistema.aprobar_reporte(1);
-            let reportes_aprobados = sistema.obtener_reportes_aprobados();
-            match reportes_aprobados {
-                Ok(_) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            let reportes_aprobados = match reportes_aprobados{
-                Ok(dato) => dato,
-                Err(_e) => Vec::new(),
-            };
-            let mut vec_normal : Vec<AccountId> = Vec::new();
-            vec_normal.push(accounts.frank);
-            vec_normal.push(accounts.django);
-         
-            assert_eq!(reportes_aprobados, vec_normal);
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            let fin = sistema.elecciones[0].get_fin();
+            let dia:u64 = 86_400_000;
+
+            //la eleccion se pausa y se reanuda mientras el sistema esta pausado
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+dia);
+            assert_eq!(sistema.pausar_sistema(String::from("Error")),Ok(()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+2*dia);
+            assert_eq!(sistema.pausar_eleccion(1, String::from("Error")),Ok(()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+3*dia);
+            assert_eq!(sistema.reanudar_eleccion(1, true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_fin(),fin);//lo cubre la pausa del sistema
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+5*dia);
+            assert_eq!(sistema.reanudar_sistema(true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_fin(),fin+4*dia as i64);
+
+            //el sistema se pausa y se reanuda mientras la eleccion esta pausada
+            let fin = sistema.elecciones[0].get_fin();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+6*dia);
+            assert_eq!(sistema.pausar_eleccion(1, String::from("Error")),Ok(()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+7*dia);
+            assert_eq!(sistema.pausar_sistema(String::from("Error")),Ok(()));
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+8*dia);
+            assert_eq!(sistema.reanudar_sistema(true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_fin(),fin);//lo cubre la pausa de la eleccion
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio+9*dia);
+            assert_eq!(sistema.reanudar_eleccion(1, true),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_fin(),fin+3*dia as i64);
         }
 
         #[ink::test]
-        fn test_get_reportes_rechazados() {
+        fn deposito_de_candidatura(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let contrato = ink::env::test::callee::<ink::env::DefaultEnvironment>();
             let mut sistema = SistemaDeVotacion::new();
-            sistema.agregar_accountid_de_reporte(accounts.frank);
-            sistema.agregar_accountid_de_reporte(accounts.django);
-            assert_eq!(sistema.reporte_sin_permiso.len(),2);
-            sistema.rechazar_reporte(1);
-            assert_eq!(sistema.reporte_sin_permiso.len(),1);
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            assert_eq!(sistema.configurar_deposito(1, 100, 5000),Ok(()));
+            assert_eq!(sistema.configurar_tesoreria(accounts.django),Ok(()));
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrar_usuario(String::from("Luis"), String::from("Perez"),String::from("3345678"));//user 3
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(50);
+            assert_eq!(sistema.postulacion_de_usuario(2, 1, false, None),Err(String::from("El monto transferido no es el deposito de la eleccion")));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            assert_eq!(sistema.postulacion_de_usuario(2, 1, false, None),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(sistema.postulacion_de_usuario(3, 1, false, None),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None),Err(String::from("Los votantes no pagan deposito")));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None),Ok(()));
+            assert!(sistema.configurar_deposito(1, 10, 0).is_err());
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            let _ = sistema.validar_usuario(3, 1, false);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contrato, 10_000_000);
+
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio);
+            assert!(sistema.votar_a_candidato(1, 1, 1, Hash::default()).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(sistema.retirar_deposito(2, 1),Err(String::from("La eleccion todavia no termino")));
+
+            let fin = sistema.elecciones[0].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            let saldo_bob = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            assert_eq!(sistema.retirar_deposito(2, 1),Ok(100));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap(),saldo_bob+100);
+            assert_eq!(sistema.retirar_deposito(2, 1),Err(String::from("El deposito ya fue liquidado")));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(sistema.retirar_deposito(3, 1).is_err());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let saldo_tesoreria = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.django).unwrap();
+            assert_eq!(sistema.liquidar_depositos(1),Ok(100));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.django).unwrap(),saldo_tesoreria+100);
+            let estados:Vec<EstadoDeDeposito> = sistema.elecciones[0].get_depositos().iter().map(|d| d.get_estado()).collect();
+            assert_eq!(estados,vec![EstadoDeDeposito::Reintegrado,EstadoDeDeposito::Perdido]);
         }
 
         #[ink::test]
-        fn test_get_reportes_rechazados_sin_permisos() {
+        fn deposito_cuenta_los_votos_a_listas(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let contrato = ink::env::test::callee::<ink::env::DefaultEnvironment>();
             let mut sistema = SistemaDeVotacion::new();
-            sistema.agregar_accountid_de_reporte(accounts.frank);
-            sistema.agregar_accountid_de_reporte(accounts.django);
-            assert_eq!(sistema.reporte_sin_permiso.len(),2);
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            assert_eq!(sistema.configurar_deposito(1, 100, 5000),Ok(()));
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            sistema.rechazar_reporte(1);
-            assert_eq!(sistema.reporte_sin_permiso.len(),2);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            assert_eq!(sistema.postulacion_de_usuario(2, 1, false, None),Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(sistema.postulacion_de_usuario(1, 1, true, None),Ok(()));
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            assert!(sistema.crear_lista(1, String::from("Lista Azul"), vec![1]).is_ok());
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contrato, 10_000_000);
+
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio);
+            assert!(sistema.votar_a_lista(1, 1, 1, Hash::default()).is_ok());
+            assert_eq!(sistema.elecciones[0].candidatos[0].get_cantidad_votos(),0);
+
+            let fin = sistema.elecciones[0].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(sistema.retirar_deposito(2, 1),Ok(100));
         }
 
         #[ink::test]
-        fn get_reporte_eleccion_eleccion_abierta(){
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_719_900_000_000);
+        fn finalizar_eleccion_congela_el_resultado(){
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let mut sistema = SistemaDeVotacion::new();
-            sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
-            let res = sistema.obtener_reporte_de_eleccion(1);
-            match res {
-                Ok(_) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            assert!(res.is_err())
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            sistema.registrar_usuario(String::from("Luis"), String::from("Perez"),String::from("3345678"));//user 3
+            let _ = sistema.postulacion_de_usuario(3, 1, false, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let _ = sistema.postulacion_de_usuario(2, 1, true, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let _ = sistema.postulacion_de_usuario(1, 1, true, None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(2, 1, true);
+            let _ = sistema.validar_usuario(3, 1, true);
+
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio);
+            assert!(sistema.votar_a_candidato(1, 1, 1, Hash::default()).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(sistema.votar_en_blanco(2, 1, Hash::default()).is_ok());
+            assert_eq!(sistema.votar_en_blanco(2, 1, Hash::default()),Err(String::from("El votante ya voto")));
+            assert_eq!(sistema.finalizar_eleccion(1),Err(String::from("La eleccion todavia no termino")));
+            assert!(sistema.hash_del_resultado(1).is_err());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.hash_del_resultado(1),Ok(None));
+
+            let fin = sistema.elecciones[0].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            let hash = sistema.finalizar_eleccion(1).unwrap();
+            assert!(sistema.finalizar_eleccion(1).is_err());
+            assert_eq!(sistema.hash_del_resultado(1),Ok(Some(hash)));
+            assert!(sistema.hash_del_resultado(2).is_err());
+            let (resultado, hash_guardado) = sistema.resultado_final(1).unwrap();
+            assert_eq!(hash, hash_guardado);
+            assert_eq!(resultado.get_votos_emitidos(),2);
+            assert_eq!(resultado.get_votos_en_blanco(),1);
+            assert_eq!(resultado.get_cantidad_de_votantes(),2);
+            assert_eq!(resultado.get_ganadores(),vec![Some(1)]);
+
+            //los getters sirven el resultado congelado aunque el almacenamiento cambie
+            sistema.elecciones[0].candidatos[0].cant_votos = 50;
+            assert_eq!(sistema.elecciones[0].get_candidatos()[0].get_cantidad_votos(),1);
+            let mut salida = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&resultado, &mut salida);
+            assert_eq!(Hash::from(salida), hash);
+
+            assert_eq!(sistema.eliminar_eleccion(1),Err(String::from("La eleccion esta finalizada")));
+            assert_eq!(sistema.pausar_eleccion(1, String::from("Error")),Err(String::from("La eleccion esta finalizada")));
         }
 
         #[ink::test]
-        fn get_reporte_eleccion_reporte_con_permiso(){
+        fn certificacion_de_auditores(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_900_900_000_000);
             let mut sistema = SistemaDeVotacion::new();
-            sistema.agregar_accountid_de_reporte(accounts.charlie);
-            sistema.aprobar_reporte(1);
-            sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
+            assert!(sistema.configurar_auditores(1, vec![accounts.bob, accounts.bob], 1).is_err());
+            assert!(sistema.configurar_auditores(1, vec![accounts.bob, accounts.charlie], 3).is_err());
+            assert_eq!(sistema.configurar_auditores(1, vec![accounts.bob, accounts.charlie, accounts.django], 2),Ok(()));
+            assert_eq!(sistema.elecciones[0].get_estado_de_certificacion(),EstadoDeCertificacion::SinFinalizar);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(sistema.certificar_resultado(1),Err(String::from("La eleccion no esta finalizada")));
+            let fin = sistema.elecciones[0].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            assert!(sistema.finalizar_eleccion(1).is_ok());
+
+            assert_eq!(sistema.certificar_resultado(1),Ok(EstadoDeCertificacion::Pendiente));
+            assert_eq!(sistema.disputar_resultado(1, String::from("Error")),Err(String::from("El auditor ya se expidio sobre el resultado")));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(sistema.certificar_resultado(1),Err(String::from("No es auditor de la eleccion")));
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            let res = sistema.obtener_reporte_de_eleccion(1);
-            match res {
-                Ok(_) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            assert!(res.is_ok())
+            assert_eq!(sistema.disputar_resultado(1, String::from("Faltan votos")),Ok(EstadoDeCertificacion::Disputada));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(sistema.certificar_resultado(1),Ok(EstadoDeCertificacion::Certificada));
+            assert_eq!(sistema.elecciones[0].get_firmantes(),vec![accounts.bob, accounts.django]);
+            assert_eq!(sistema.elecciones[0].get_disputas()[0].get_auditor(),accounts.charlie);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.configurar_auditores(1, vec![accounts.eve], 1),Err(String::from("La eleccion esta finalizada")));
         }
 
         #[ink::test]
-        fn get_reporte_eleccion_reporte_sin_permiso(){
+        fn comprobantes_de_voto(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_900_900_000_000);
             let mut sistema = SistemaDeVotacion::new();
-            sistema.agregar_accountid_de_reporte(accounts.charlie);
-            sistema.rechazar_reporte(1);
-            sistema.crear_eleccion(String::from("CEO de Intel"), 01, 07, 2024, 20, 07, 2024);//elec 1
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            let res = sistema.obtener_reporte_de_eleccion(1);
-            match res {
-                Ok(_) => ink::env::debug_message("SE PUEDE "),
-                Err(ref e) => ink::env::debug_message(&e),
-            }
-            assert!(res.is_err())
+            let _ = sistema.crear_eleccion(String::from("Vocal"), 15, 10, 2024, 20, 11, 2024);//elec 1, se elimina despues de votar
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 2
+            sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            let _ = sistema.postulacion_de_usuario(2, 2, true, None);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let _ = sistema.postulacion_de_usuario(1, 2, true, None);
+            let _ = sistema.validar_usuario(1, 2, true);
+            let _ = sistema.validar_usuario(2, 2, true);
+
+            let inicio = sistema.elecciones[1].get_inicio() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio);
+            let acumulador_inicial = sistema.elecciones[1].get_acumulador_de_comprobantes();
+            let nonce:Hash = [0x07; 32].into();
+            let comprobante_1 = sistema.votar_en_blanco(1, 2, nonce).unwrap();
+            let hash = |datos:&(u32, [u8; 32], Hash)| {
+                let mut salida = <Blake2x256 as HashOutput>::Type::default();
+                ink::env::hash_encoded::<Blake2x256, _>(datos, &mut salida);
+                salida
+            };
+            let mut nulificador = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&(2u32, accounts.alice), &mut nulificador);
+            assert_eq!(comprobante_1, Hash::from(hash(&(2, nulificador, nonce))));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let comprobante_2 = sistema.votar_en_blanco(2, 2, nonce).unwrap();
+            assert_ne!(comprobante_1, comprobante_2);
+            assert_ne!(sistema.elecciones[1].get_acumulador_de_comprobantes(), acumulador_inicial);
+            assert_eq!(sistema.elecciones[1].get_cantidad_de_comprobantes(),2);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(sistema.eliminar_eleccion(1),Ok(()));
+            let inclusion = sistema.verificar_comprobante(1, comprobante_1).unwrap().unwrap();
+            assert_eq!((inclusion.get_posicion(), inclusion.get_acumulador_previo()), (1, Hash::default()));
+            let mut acumulador = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&(inclusion.get_acumulador_previo(), comprobante_1), &mut acumulador);
+            assert_eq!(inclusion.get_acumulador(), Hash::from(acumulador));
+            assert_eq!(inclusion.get_cantidad_de_comprobantes(), 2);
+            assert_eq!(inclusion.get_acumulador_final(), sistema.elecciones[0].get_acumulador_de_comprobantes());
+            assert_eq!(sistema.verificar_comprobante(1, [0x01; 32].into()),Ok(None));
+            assert!(sistema.verificar_comprobante(2, comprobante_1).is_err());
+
+            let fin = sistema.elecciones[0].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            sistema.finalizar_eleccion(1).unwrap();
+            let (resultado, _) = sistema.resultado_final(1).unwrap();
+            assert_eq!(resultado.get_acumulador_de_comprobantes(), sistema.elecciones[0].get_acumulador_de_comprobantes());
+            assert_eq!(resultado.get_cantidad_de_comprobantes(), 2);
+            let inclusion = sistema.verificar_comprobante(1, comprobante_2).unwrap().unwrap();
+            assert_eq!(inclusion.get_posicion(), 2);
+            assert_eq!(inclusion.get_acumulador(), resultado.get_acumulador_de_comprobantes());
+
+            //un comprobante alterado no esta registrado
+            let mut alterado = comprobante_1;
+            alterado.as_mut()[0] ^= 0x01;
+            assert_eq!(sistema.verificar_comprobante(1, alterado),Ok(None));
+            //uno falsificado en una posicion no llega al acumulador del resultado final
+            let clave = sistema.elecciones[0].clave;
+            let falsificado:Hash = [0x09; 32].into();
+            sistema.comprobantes.insert((clave, falsificado), &1);
+            assert_eq!(sistema.verificar_comprobante(1, falsificado),Err(String::from("La cadena de comprobantes no coincide con el acumulador de la eleccion")));
+            //y si se altera un comprobante guardado, los anteriores ya no verifican contra el resultado final
+            sistema.comprobantes_por_posicion.insert((clave, 2), &falsificado);
+            assert!(sistema.verificar_comprobante(1, comprobante_1).is_err());
+            assert!(sistema.verificar_comprobante(1, comprobante_2).is_ok());
         }
 
         #[ink::test]
-        fn probando_errores(){
+        fn eventos_para_el_indexador(){
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_900_900_000_000);
             let mut sistema = SistemaDeVotacion::new();
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 01, 2024, 20, 01, 2024);
+            let _ = sistema.crear_eleccion(String::from("Presidente"), 15, 10, 2024, 20, 11, 2024);//elec 1
             sistema.registrar_usuario(String::from("Carlos"), String::from("Sanchez"),String::from("7654456"));//user 1
-            sistema.postulacion_de_usuario(1,1,false);
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 01, 2024, 20, 23, 2024);
-            assert!(res.is_err());
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 01, 1969, 20, 02, 2024);
-            assert!(res.is_err());
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 03, 2024, 20, 02, 2024);
-            assert!(res.is_err());
-            let res = sistema.crear_eleccion(String::from("CEO de Intel"), 01, 03, 2024, 20, 02, 1969);
-            assert!(res.is_err());
-            let res = sistema.obtener_reporte_de_eleccion(3);
-            assert!(res.is_err());
-            let res = sistema.validar_usuario(1, 1, true);
-            assert!(res.is_err());
-            let res = sistema.validar_usuario(1, 2, true);
-            assert!(res.is_err());
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            let res = sistema.eliminar_eleccion(1);
-            assert!(res.is_err());
-            let res = sistema.obtener_reportes_aprobados();
-            assert!(res.is_err());
-            let res = sistema.obtener_reporte_de_eleccion(3);
-            assert!(res.is_err());
-            let res = sistema.validar_usuario(1, 1, true);
-            assert!(res.is_err());
+            sistema.registrar_usuario(String::from("Ana"), String::from("Erazo"),String::from("1245623"));//user 2
+            let _ = sistema.postulacion_de_usuario(1, 1, true, None);
+            let _ = sistema.postulacion_de_usuario(2, 1, true, None);
+            let _ = sistema.validar_usuario(1, 1, true);
+            let _ = sistema.validar_usuario(1, 1, true);//ya aprobado, no emite de nuevo
+            let _ = sistema.rechazar_usuario(2, 1, MotivoDeRechazo::DatosIncompletos);
+            let inicio = sistema.elecciones[0].get_inicio() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(inicio);
+            let comprobante = sistema.votar_en_blanco(1, 1, Hash::default()).unwrap();
+            let fin = sistema.elecciones[0].get_fin() as u64;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(fin+1);
+            let hash = sistema.finalizar_eleccion(1).unwrap();
+            sistema.agregar_accountid_de_reporte(accounts.django);
+            let _ = sistema.aprobar_reporte(1);
+
+            let eventos:Vec<_> = ink::env::test::recorded_events().collect();
+            let firmas:Vec<_> = eventos.iter().map(|e| e.topics[0].clone()).collect();
+            let firma = |topico:Option<[u8;32]>| topico.unwrap().to_vec();
+            assert_eq!(firmas, vec![
+                firma(<EleccionCreada as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<UsuarioRegistrado as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<UsuarioRegistrado as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<UsuarioPostulado as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<UsuarioPostulado as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<PostulacionResuelta as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<PostulacionResuelta as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<VotoEmitido as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<EleccionFinalizada as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<ReporteSolicitado as ink::env::Event>::SIGNATURE_TOPIC),
+                firma(<ReporteResuelto as ink::env::Event>::SIGNATURE_TOPIC),
+            ]);
+            let creada = <EleccionCreada as scale::Decode>::decode(&mut &eventos[0].data[..]).unwrap();
+            assert_eq!((creada.id_eleccion, creada.cargo, creada.inicio), (1, String::from("Presidente"), inicio as i64));
+            let rechazo = <PostulacionResuelta as scale::Decode>::decode(&mut &eventos[6].data[..]).unwrap();
+            assert_eq!((rechazo.id_usuario, rechazo.aprobada), (2, false));
+            let voto = <VotoEmitido as scale::Decode>::decode(&mut &eventos[7].data[..]).unwrap();
+            assert_eq!(voto.comprobante, comprobante);
+            let finalizada = <EleccionFinalizada as scale::Decode>::decode(&mut &eventos[8].data[..]).unwrap();
+            assert_eq!(finalizada.hash_del_resultado, hash);
         }
     }
 }
\ No newline at end of file